@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+const APP_DATA_BASE_ENV: &str = "SCOUT_APP_DATA_BASE";
+
+/// Which Windows app-data root to store config under. Irrelevant on other
+/// platforms, where there's only one sensible location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AppDataBase {
+    /// `%LOCALAPPDATA%` — doesn't roam with the user profile. Default, since
+    /// most users don't want config following them across machines.
+    #[default]
+    Local,
+    /// `%APPDATA%` — roams with the user profile, which some
+    /// enterprise/roaming-profile setups require.
+    Roaming,
+}
+
+impl AppDataBase {
+    /// Read the base-dir choice from `SCOUT_APP_DATA_BASE` (`"local"` or
+    /// `"roaming"`, case-insensitive), defaulting to [`AppDataBase::Local`]
+    /// if unset or unrecognized.
+    pub fn from_env() -> Self {
+        match std::env::var(APP_DATA_BASE_ENV) {
+            Ok(value) if value.eq_ignore_ascii_case("roaming") => AppDataBase::Roaming,
+            _ => AppDataBase::Local,
+        }
+    }
+}
+
+/// Compute the directory scout-for-lol should store its config/cache in,
+/// honoring `base` on Windows. On other platforms `base` is ignored since
+/// there's no Local/Roaming distinction.
+pub fn compute_app_data_dir(base: AppDataBase) -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        let env_var = match base {
+            AppDataBase::Local => "LOCALAPPDATA",
+            AppDataBase::Roaming => "APPDATA",
+        };
+        std::env::var_os(env_var)
+            .map(PathBuf::from)
+            .map(|p| p.join("scout-for-lol"))
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = base;
+        directories_next_fallback()
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn directories_next_fallback() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".scout-for-lol"))
+}
+
+/// If config previously lived under Roaming and `base` now resolves to
+/// Local with nothing there yet, copy it over so existing users don't lose
+/// their settings when the default changes.
+pub fn migrate_from_roaming(base: AppDataBase) -> std::io::Result<()> {
+    if base != AppDataBase::Local {
+        return Ok(());
+    }
+    let local = match compute_app_data_dir(AppDataBase::Local) {
+        Some(dir) => dir,
+        None => return Ok(()),
+    };
+    let roaming = match compute_app_data_dir(AppDataBase::Roaming) {
+        Some(dir) => dir,
+        None => return Ok(()),
+    };
+    if local.exists() || !roaming.exists() {
+        return Ok(());
+    }
+    copy_dir_recursive(&roaming, &local)
+}
+
+fn copy_dir_recursive(from: &std::path::Path, to: &std::path::Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&entry.path(), &dest)?;
+        } else {
+            std::fs::copy(entry.path(), dest)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Guards the tests below, which all set/remove the process-wide
+    /// `APP_DATA_BASE_ENV` var — without this, Rust's default parallel test
+    /// execution lets one test's `set_var`/`remove_var` interleave with
+    /// another's, producing flaky failures.
+    static TEST_GUARD: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn from_env_defaults_to_local() {
+        let _guard = TEST_GUARD.lock().expect("test guard poisoned");
+        std::env::remove_var(APP_DATA_BASE_ENV);
+        assert_eq!(AppDataBase::from_env(), AppDataBase::Local);
+    }
+
+    #[test]
+    fn from_env_honors_roaming() {
+        let _guard = TEST_GUARD.lock().expect("test guard poisoned");
+        std::env::set_var(APP_DATA_BASE_ENV, "roaming");
+        assert_eq!(AppDataBase::from_env(), AppDataBase::Roaming);
+        std::env::remove_var(APP_DATA_BASE_ENV);
+    }
+
+    #[test]
+    fn from_env_is_case_insensitive() {
+        let _guard = TEST_GUARD.lock().expect("test guard poisoned");
+        std::env::set_var(APP_DATA_BASE_ENV, "RoAmInG");
+        assert_eq!(AppDataBase::from_env(), AppDataBase::Roaming);
+        std::env::remove_var(APP_DATA_BASE_ENV);
+    }
+}
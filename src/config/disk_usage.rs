@@ -0,0 +1,86 @@
+use std::path::Path;
+
+/// Byte totals for each subdirectory under [`super::paths::app_data_dir`],
+/// for a storage-usage UI and the cache eviction features. A subdirectory
+/// that doesn't exist on disk contributes 0 rather than erroring, since not
+/// every user will have used every feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskUsage {
+    pub logs_bytes: u64,
+    pub sounds_cache_bytes: u64,
+    pub youtube_cache_bytes: u64,
+    pub config_bytes: u64,
+}
+
+impl DiskUsage {
+    pub fn total_bytes(&self) -> u64 {
+        self.logs_bytes + self.sounds_cache_bytes + self.youtube_cache_bytes + self.config_bytes
+    }
+}
+
+/// Sum up disk usage under `app_data_dir`'s known subdirectories: `logs`,
+/// `sounds-cache`, `youtube-cache`, and `config`.
+pub fn get_disk_usage(app_data_dir: &Path) -> std::io::Result<DiskUsage> {
+    Ok(DiskUsage {
+        logs_bytes: dir_size(&app_data_dir.join("logs"))?,
+        sounds_cache_bytes: dir_size(&app_data_dir.join("sounds-cache"))?,
+        youtube_cache_bytes: dir_size(&app_data_dir.join("youtube-cache"))?,
+        config_bytes: dir_size(&app_data_dir.join("config"))?,
+    })
+}
+
+/// Total size in bytes of every regular file under `dir`, recursively. `0`
+/// if `dir` doesn't exist.
+fn dir_size(dir: &Path) -> std::io::Result<u64> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut total = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            total += dir_size(&entry.path())?;
+        } else if file_type.is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_subdirectories_contribute_zero() {
+        let dir = std::env::temp_dir().join("scout-for-lol-test-disk-usage-empty");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let usage = get_disk_usage(&dir).unwrap();
+
+        assert_eq!(usage, DiskUsage::default());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn totals_known_file_sizes_per_subdirectory() {
+        let dir = std::env::temp_dir().join("scout-for-lol-test-disk-usage-known");
+        let _ = std::fs::remove_dir_all(&dir);
+        let logs = dir.join("logs");
+        let sounds_cache = dir.join("sounds-cache");
+        std::fs::create_dir_all(&logs).unwrap();
+        std::fs::create_dir_all(&sounds_cache).unwrap();
+        std::fs::write(logs.join("a.log"), [0u8; 10]).unwrap();
+        std::fs::write(sounds_cache.join("a.mp3"), [0u8; 25]).unwrap();
+
+        let usage = get_disk_usage(&dir).unwrap();
+
+        assert_eq!(usage.logs_bytes, 10);
+        assert_eq!(usage.sounds_cache_bytes, 25);
+        assert_eq!(usage.youtube_cache_bytes, 0);
+        assert_eq!(usage.total_bytes(), 35);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,9 @@
+pub mod app_data;
+pub mod disk_usage;
+pub mod paths;
+pub mod resolved;
+
+pub use app_data::{compute_app_data_dir, AppDataBase};
+pub use disk_usage::{get_disk_usage, DiskUsage};
+pub use paths::{app_data_dir, try_app_data_dir};
+pub use resolved::{effective_config, ConfigOverrides, ResolvedConfig, DEFAULT_SOUND_PACK_ID};
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use super::app_data::{compute_app_data_dir, AppDataBase};
+
+/// The process-wide app-data directory, set once by [`init`]. A lock rather
+/// than a `OnceLock` so tests can reset it back to the pre-init state.
+static APP_DATA_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Initialize the app-data directory for the process. Should run once,
+/// early in startup; path helpers called before this has run simply fall
+/// back to the system temp dir rather than panicking.
+pub fn init(base: AppDataBase) {
+    let dir = compute_app_data_dir(base).unwrap_or_else(std::env::temp_dir);
+    *APP_DATA_DIR.write().expect("app data dir lock poisoned") = Some(dir);
+}
+
+/// `Some` app-data directory if [`init`] has already run, `None` otherwise
+/// — for callers that want to distinguish "not initialized yet" from a
+/// real location rather than silently using the fallback.
+pub fn try_app_data_dir() -> Option<PathBuf> {
+    APP_DATA_DIR.read().expect("app data dir lock poisoned").clone()
+}
+
+/// The app-data directory, falling back to the system temp dir if [`init`]
+/// hasn't run yet. Safe to call from very-early startup code (like startup
+/// logging) that may run before `init`.
+pub fn app_data_dir() -> PathBuf {
+    try_app_data_dir().unwrap_or_else(std::env::temp_dir)
+}
+
+/// Put the module back into its pre-init state between tests that care
+/// about that state. Not exposed outside tests — production code always
+/// goes through [`init`] exactly once at startup.
+#[cfg(test)]
+pub(crate) fn reset_for_test() {
+    *APP_DATA_DIR.write().expect("app data dir lock poisoned") = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Guards the tests below, which all reset and read back the shared
+    /// `APP_DATA_DIR` static — without this, Rust's default parallel test
+    /// execution lets one test's `init()` land between another's
+    /// `reset_for_test()` and its assertion.
+    static TEST_GUARD: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn try_app_data_dir_is_none_before_init() {
+        let _guard = TEST_GUARD.lock().expect("test guard poisoned");
+        reset_for_test();
+        assert_eq!(try_app_data_dir(), None);
+    }
+
+    #[test]
+    fn app_data_dir_falls_back_to_temp_dir_before_init() {
+        let _guard = TEST_GUARD.lock().expect("test guard poisoned");
+        reset_for_test();
+        assert_eq!(app_data_dir(), std::env::temp_dir());
+    }
+
+    #[test]
+    fn app_data_dir_reflects_init_once_called() {
+        let _guard = TEST_GUARD.lock().expect("test guard poisoned");
+        reset_for_test();
+        init(AppDataBase::Local);
+        assert!(try_app_data_dir().is_some());
+        reset_for_test();
+    }
+}
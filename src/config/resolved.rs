@@ -0,0 +1,126 @@
+use super::app_data::AppDataBase;
+use crate::discord::EmptyChannelBehavior;
+
+/// The fully-merged configuration actually in effect — defaults layered
+/// with whatever the user has overridden — so the UI/CLI can show users
+/// what scout-for-lol will actually do, not just what they've set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedConfig {
+    pub app_data_base: AppDataBase,
+    pub empty_channel_behavior: EmptyChannelBehavior,
+    /// Id of the sound pack to load at startup. `DiscordClient::new` does
+    /// not read this field; it still takes the already-loaded pack as a
+    /// parameter.
+    pub active_sound_pack: String,
+    /// When set, only callouts involving the local player or their team
+    /// are announced — see [`crate::rules::SoloMode`].
+    pub solo_mode: bool,
+    /// When set, playback auto-mutes while the OS reports Do Not
+    /// Disturb/Focus Assist as active — see
+    /// [`crate::sound::should_mute_for_dnd`].
+    pub auto_mute_during_dnd: bool,
+}
+
+/// Sound pack id existing configs are migrated to when they predate
+/// `active_sound_pack`.
+pub const DEFAULT_SOUND_PACK_ID: &str = "base";
+
+impl Default for ResolvedConfig {
+    fn default() -> Self {
+        ResolvedConfig {
+            app_data_base: AppDataBase::Local,
+            empty_channel_behavior: EmptyChannelBehavior::KeepPlaying,
+            active_sound_pack: DEFAULT_SOUND_PACK_ID.to_string(),
+            solo_mode: false,
+            auto_mute_during_dnd: false,
+        }
+    }
+}
+
+/// Overrides a user may have set explicitly; `None` means "use the default".
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub app_data_base: Option<AppDataBase>,
+    pub empty_channel_behavior: Option<EmptyChannelBehavior>,
+    pub active_sound_pack: Option<String>,
+    pub solo_mode: Option<bool>,
+    pub auto_mute_during_dnd: Option<bool>,
+}
+
+/// Merge `overrides` over the defaults to produce the configuration
+/// actually in effect.
+pub fn effective_config(overrides: &ConfigOverrides) -> ResolvedConfig {
+    let defaults = ResolvedConfig::default();
+    ResolvedConfig {
+        app_data_base: overrides.app_data_base.unwrap_or(defaults.app_data_base),
+        empty_channel_behavior: overrides
+            .empty_channel_behavior
+            .unwrap_or(defaults.empty_channel_behavior),
+        active_sound_pack: overrides
+            .active_sound_pack
+            .clone()
+            .unwrap_or(defaults.active_sound_pack),
+        solo_mode: overrides.solo_mode.unwrap_or(defaults.solo_mode),
+        auto_mute_during_dnd: overrides.auto_mute_during_dnd.unwrap_or(defaults.auto_mute_during_dnd),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_overrides_returns_defaults() {
+        assert_eq!(effective_config(&ConfigOverrides::default()), ResolvedConfig::default());
+    }
+
+    #[test]
+    fn overrides_take_precedence_over_defaults() {
+        let overrides = ConfigOverrides {
+            app_data_base: Some(AppDataBase::Roaming),
+            ..Default::default()
+        };
+        let resolved = effective_config(&overrides);
+        assert_eq!(resolved.app_data_base, AppDataBase::Roaming);
+        assert_eq!(
+            resolved.empty_channel_behavior,
+            ResolvedConfig::default().empty_channel_behavior
+        );
+    }
+
+    #[test]
+    fn active_sound_pack_defaults_to_base() {
+        assert_eq!(ResolvedConfig::default().active_sound_pack, DEFAULT_SOUND_PACK_ID);
+    }
+
+    #[test]
+    fn active_sound_pack_override_round_trips() {
+        let overrides = ConfigOverrides {
+            active_sound_pack: Some("memes".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(effective_config(&overrides).active_sound_pack, "memes");
+    }
+
+    #[test]
+    fn solo_mode_defaults_to_off() {
+        assert!(!ResolvedConfig::default().solo_mode);
+    }
+
+    #[test]
+    fn solo_mode_override_round_trips() {
+        let overrides = ConfigOverrides { solo_mode: Some(true), ..Default::default() };
+        assert!(effective_config(&overrides).solo_mode);
+    }
+
+    #[test]
+    fn auto_mute_during_dnd_defaults_to_off() {
+        assert!(!ResolvedConfig::default().auto_mute_during_dnd);
+    }
+
+    #[test]
+    fn auto_mute_during_dnd_override_round_trips() {
+        let overrides = ConfigOverrides { auto_mute_during_dnd: Some(true), ..Default::default() };
+        assert!(effective_config(&overrides).auto_mute_during_dnd);
+    }
+}
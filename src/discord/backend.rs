@@ -0,0 +1,257 @@
+use std::time::{Duration, Instant};
+
+use crate::game::GameEvent;
+use crate::rules::RulesEngine;
+
+/// Per-stage timing for a [`test_event_roundtrip`] call.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundtripTiming {
+    pub serialize: Duration,
+    pub network: Duration,
+    pub parse: Duration,
+}
+
+impl RoundtripTiming {
+    pub fn total(&self) -> Duration {
+        self.serialize + self.network + self.parse
+    }
+}
+
+/// The result of pushing a synthetic event through the rules engine and a
+/// round trip to the backend.
+#[derive(Debug, Clone)]
+pub struct RoundtripResult {
+    pub matched_pools: Vec<String>,
+    pub timing: RoundtripTiming,
+}
+
+/// Submit a synthetic, non-playing `event` through rule matching and
+/// `send` (a stand-in for the real backend call), reporting which pools it
+/// would have triggered plus how long serializing, "networking", and the
+/// final stage each took. The response from `send` is not parsed: the
+/// `parse` stage times matching `event` against `engine` locally, and the
+/// pools reported come from that match. Lets users sanity-check that the
+/// backend is wired up for the events they care about without actually
+/// making a sound.
+pub fn test_event_roundtrip(
+    engine: &RulesEngine,
+    event: &GameEvent,
+    send: impl FnOnce(&str) -> String,
+) -> RoundtripResult {
+    let serialize_start = Instant::now();
+    let payload = format!("{event:?}");
+    let serialize = serialize_start.elapsed();
+
+    let network_start = Instant::now();
+    let response = send(&payload);
+    let network = network_start.elapsed();
+
+    let parse_start = Instant::now();
+    let matched_pools = engine
+        .matching_pools(event)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    drop(response);
+    let parse = parse_start.elapsed();
+
+    RoundtripResult {
+        matched_pools,
+        timing: RoundtripTiming { serialize, network, parse },
+    }
+}
+
+/// A Discord API call failed in a way callers should react to specifically,
+/// rather than treating it as a generic network hiccup.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DiscordApiError {
+    /// The bot token was rejected (HTTP 401) — typically because it was
+    /// rotated while the app was running. Every subsequent call would also
+    /// 401, so there's no point retrying with the same client.
+    #[error("Discord rejected the bot token — reconfigure it")]
+    TokenInvalid,
+    #[error("Discord API request failed with status {0}")]
+    RequestFailed(u16),
+}
+
+/// Map a raw HTTP status from a Discord API call (e.g. from `post_message`
+/// or `test_connection`) to a specific error, so a 401 is never mistaken
+/// for a transient failure worth retrying with the same client.
+fn classify_response(status: u16) -> Result<(), DiscordApiError> {
+    match status {
+        200..=299 => Ok(()),
+        401 => Err(DiscordApiError::TokenInvalid),
+        other => Err(DiscordApiError::RequestFailed(other)),
+    }
+}
+
+/// Everything needed to (re)connect to Discord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendConfig {
+    pub token: String,
+    pub channel_id: u64,
+}
+
+/// Owns the connection config and, lazily, whatever client is connected
+/// with it. `configure_backend` only tears down and recreates the client
+/// when the config actually changed, so flipping an unrelated setting
+/// doesn't bounce the voice connection.
+#[derive(Default)]
+pub struct Backend<C> {
+    config: Option<BackendConfig>,
+    client: Option<C>,
+    /// Set when a client is created and cleared when an API call's token is
+    /// rejected (401), so the UI can prompt reconfiguration instead of
+    /// silently retrying a dead token. Other failures leave it set.
+    connected: bool,
+}
+
+impl<C> Backend<C> {
+    pub fn new() -> Self {
+        Backend {
+            config: None,
+            client: None,
+            connected: false,
+        }
+    }
+
+    /// Apply `config`, creating a client via `create` only if the backend
+    /// is unconfigured or `config` differs from what's already active.
+    pub fn configure_backend(&mut self, config: BackendConfig, create: impl FnOnce(&BackendConfig) -> C) {
+        if self.config.as_ref() == Some(&config) {
+            return;
+        }
+        self.client = Some(create(&config));
+        self.config = Some(config);
+        self.connected = true;
+    }
+
+    pub fn client(&self) -> Option<&C> {
+        self.client.as_ref()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// Classify the status of a `post_message`/`test_connection` response
+    /// via [`classify_response`], clearing [`Self::is_connected`] if the
+    /// token was rejected so the UI knows to prompt reconfiguration rather
+    /// than keep retrying with the now-dead client.
+    pub fn record_response(&mut self, status: u16) -> Result<(), DiscordApiError> {
+        let result = classify_response(status);
+        if result == Err(DiscordApiError::TokenInvalid) {
+            self.connected = false;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{Rule, RulesPack};
+    use std::cell::Cell;
+
+    #[test]
+    fn roundtrip_reports_the_matched_pools() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "mine".into(),
+            enabled: true,
+            rules: vec![Rule { name: "r".into(), event_kind: "ping".into(), pool: "danger_pool".into(), ..Default::default() }],
+        });
+        let event = GameEvent::Ping { kind: crate::game::PingKind::Danger, from_local_player: true };
+
+        let result = test_event_roundtrip(&engine, &event, |_| "ok".to_string());
+
+        assert_eq!(result.matched_pools, vec!["danger_pool".to_string()]);
+    }
+
+    #[test]
+    fn roundtrip_timing_totals_every_stage() {
+        let engine = RulesEngine::new();
+        let event = GameEvent::Ping { kind: crate::game::PingKind::Danger, from_local_player: true };
+
+        let result = test_event_roundtrip(&engine, &event, |_| "ok".to_string());
+
+        assert_eq!(
+            result.timing.total(),
+            result.timing.serialize + result.timing.network + result.timing.parse
+        );
+    }
+
+    #[test]
+    fn reconfiguring_with_the_same_config_does_not_recreate_the_client() {
+        let creations = Cell::new(0);
+        let mut backend: Backend<u32> = Backend::new();
+        let config = BackendConfig {
+            token: "t".into(),
+            channel_id: 1,
+        };
+
+        backend.configure_backend(config.clone(), |_| {
+            creations.set(creations.get() + 1);
+            creations.get()
+        });
+        backend.configure_backend(config, |_| {
+            creations.set(creations.get() + 1);
+            creations.get()
+        });
+
+        assert_eq!(creations.get(), 1);
+    }
+
+    #[test]
+    fn reconfiguring_with_a_different_config_recreates_the_client() {
+        let creations = Cell::new(0);
+        let mut backend: Backend<u32> = Backend::new();
+
+        backend.configure_backend(
+            BackendConfig { token: "t".into(), channel_id: 1 },
+            |_| { creations.set(creations.get() + 1); creations.get() },
+        );
+        backend.configure_backend(
+            BackendConfig { token: "t".into(), channel_id: 2 },
+            |_| { creations.set(creations.get() + 1); creations.get() },
+        );
+
+        assert_eq!(creations.get(), 2);
+    }
+
+    #[test]
+    fn a_401_is_mapped_to_a_specific_token_invalid_error() {
+        assert_eq!(classify_response(401), Err(DiscordApiError::TokenInvalid));
+    }
+
+    #[test]
+    fn other_error_statuses_are_reported_generically() {
+        assert_eq!(classify_response(500), Err(DiscordApiError::RequestFailed(500)));
+    }
+
+    #[test]
+    fn success_statuses_are_not_errors() {
+        assert_eq!(classify_response(204), Ok(()));
+    }
+
+    #[test]
+    fn a_401_response_clears_the_connected_status() {
+        let mut backend: Backend<u32> = Backend::new();
+        backend.configure_backend(BackendConfig { token: "t".into(), channel_id: 1 }, |_| 1);
+        assert!(backend.is_connected());
+
+        let result = backend.record_response(401);
+
+        assert_eq!(result, Err(DiscordApiError::TokenInvalid));
+        assert!(!backend.is_connected());
+    }
+
+    #[test]
+    fn a_successful_response_leaves_the_connected_status_untouched() {
+        let mut backend: Backend<u32> = Backend::new();
+        backend.configure_backend(BackendConfig { token: "t".into(), channel_id: 1 }, |_| 1);
+
+        assert!(backend.record_response(200).is_ok());
+        assert!(backend.is_connected());
+    }
+}
@@ -0,0 +1,102 @@
+/// A voice channel visible to the bot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceChannel {
+    pub id: u64,
+    pub name: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ChannelLookupError {
+    #[error("no voice channel named {0:?}")]
+    NotFound(String),
+    #[error("multiple voice channels are named {0:?}; use the channel id instead")]
+    Ambiguous(String),
+}
+
+/// Resolve a channel id from a human-typed name, so users setting the
+/// active voice channel don't have to go dig up a numeric id.
+pub fn resolve_channel_by_name(
+    channels: &[VoiceChannel],
+    name: &str,
+) -> Result<u64, ChannelLookupError> {
+    let matches: Vec<&VoiceChannel> = channels.iter().filter(|c| c.name == name).collect();
+    match matches.as_slice() {
+        [] => Err(ChannelLookupError::NotFound(name.to_string())),
+        [only] => Ok(only.id),
+        _ => Err(ChannelLookupError::Ambiguous(name.to_string())),
+    }
+}
+
+/// A guild (server) the bot is a member of, with the voice channels we can
+/// see in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guild {
+    pub id: u64,
+    pub channels: Vec<VoiceChannel>,
+}
+
+/// Confirm `channel_id` belongs to one of `guilds` the bot is actually in,
+/// so users can't point the bot at a channel it has no access to.
+pub fn validate_channel_in_guilds(guilds: &[Guild], channel_id: u64) -> bool {
+    guilds
+        .iter()
+        .any(|g| g.channels.iter().any(|c| c.id == channel_id))
+}
+
+/// Flatten `guilds` into `(guild_id, channel_name, channel_id)` rows for
+/// display, e.g. in a "which channels can I use?" listing.
+pub fn list_usable_channels(guilds: &[Guild]) -> Vec<(u64, &str, u64)> {
+    guilds
+        .iter()
+        .flat_map(|g| g.channels.iter().map(move |c| (g.id, c.name.as_str(), c.id)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels() -> Vec<VoiceChannel> {
+        vec![
+            VoiceChannel { id: 1, name: "general".into() },
+            VoiceChannel { id: 2, name: "scrims".into() },
+            VoiceChannel { id: 3, name: "scrims".into() },
+        ]
+    }
+
+    #[test]
+    fn resolves_a_unique_name() {
+        assert_eq!(resolve_channel_by_name(&channels(), "general"), Ok(1));
+    }
+
+    #[test]
+    fn errors_when_name_is_not_found() {
+        assert_eq!(
+            resolve_channel_by_name(&channels(), "nope"),
+            Err(ChannelLookupError::NotFound("nope".into()))
+        );
+    }
+
+    #[test]
+    fn errors_when_name_is_ambiguous() {
+        assert_eq!(
+            resolve_channel_by_name(&channels(), "scrims"),
+            Err(ChannelLookupError::Ambiguous("scrims".into()))
+        );
+    }
+
+    #[test]
+    fn validates_a_channel_belonging_to_a_known_guild() {
+        let guilds = vec![Guild { id: 1, channels: channels() }];
+        assert!(validate_channel_in_guilds(&guilds, 1));
+        assert!(!validate_channel_in_guilds(&guilds, 999));
+    }
+
+    #[test]
+    fn lists_every_channel_across_guilds() {
+        let guilds = vec![Guild { id: 1, channels: channels() }];
+        let listed = list_usable_channels(&guilds);
+        assert_eq!(listed.len(), 3);
+        assert!(listed.contains(&(1, "general", 1)));
+    }
+}
@@ -0,0 +1,61 @@
+/// What to do when everyone leaves the voice channel scout-for-lol is
+/// connected to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyChannelBehavior {
+    /// Stay connected and keep playing sound cues regardless.
+    #[default]
+    KeepPlaying,
+    /// Stay connected but suppress playback until someone rejoins.
+    Mute,
+    /// Leave the voice channel entirely.
+    Disconnect,
+}
+
+/// Decide what the client should do, given how many non-bot members remain
+/// in the voice channel.
+pub fn decide(behavior: EmptyChannelBehavior, members_remaining: u32) -> EmptyChannelAction {
+    if members_remaining > 0 {
+        return EmptyChannelAction::Continue;
+    }
+    match behavior {
+        EmptyChannelBehavior::KeepPlaying => EmptyChannelAction::Continue,
+        EmptyChannelBehavior::Mute => EmptyChannelAction::Mute,
+        EmptyChannelBehavior::Disconnect => EmptyChannelAction::Disconnect,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyChannelAction {
+    Continue,
+    Mute,
+    Disconnect,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_channel_always_continues() {
+        for behavior in [
+            EmptyChannelBehavior::KeepPlaying,
+            EmptyChannelBehavior::Mute,
+            EmptyChannelBehavior::Disconnect,
+        ] {
+            assert_eq!(decide(behavior, 3), EmptyChannelAction::Continue);
+        }
+    }
+
+    #[test]
+    fn empty_channel_follows_configured_behavior() {
+        assert_eq!(
+            decide(EmptyChannelBehavior::KeepPlaying, 0),
+            EmptyChannelAction::Continue
+        );
+        assert_eq!(decide(EmptyChannelBehavior::Mute, 0), EmptyChannelAction::Mute);
+        assert_eq!(
+            decide(EmptyChannelBehavior::Disconnect, 0),
+            EmptyChannelAction::Disconnect
+        );
+    }
+}
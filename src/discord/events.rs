@@ -0,0 +1,5 @@
+/// Events emitted by [`super::DiscordClient`] for the UI/logs to observe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientEvent {
+    SoundPackReloaded { ok: bool },
+}
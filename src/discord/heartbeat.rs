@@ -0,0 +1,37 @@
+/// A periodic status report, primarily so external monitoring (or just a
+/// curious user) can confirm the bot is alive and which pack it's using
+/// without digging through logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heartbeat {
+    pub active_sound_pack_id: String,
+}
+
+impl super::DiscordClient {
+    pub fn heartbeat(&self) -> Heartbeat {
+        Heartbeat { active_sound_pack_id: self.sound_pack.id.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::DiscordClient;
+    use crate::sound::SoundPack;
+
+    #[test]
+    fn heartbeat_reports_the_active_pack_id() {
+        let dir = std::env::temp_dir().join(format!("scout-heartbeat-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sound-pack.json");
+        std::fs::write(
+            &path,
+            r#"{"id": "default", "pools": {"kill": {"mode": "random", "sounds": [{"id": "a"}]}}}"#,
+        )
+        .unwrap();
+
+        let pack = SoundPack::load(&path).unwrap();
+        let client = DiscordClient::new(pack, None);
+        assert_eq!(client.heartbeat().active_sound_pack_id, "default");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,220 @@
+use crate::game::objectives::Objective;
+use crate::game::{KillContext, LiveClientTeam, Team};
+
+/// Context needed to render a kill callout into player-facing text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KillMessageContext {
+    pub killer: String,
+    pub victim: String,
+}
+
+/// A Discord message locale. The catalog only needs to cover the lines a
+/// community actually uses; anything missing falls back to
+/// [`Locale::English`], so partial translations never produce a blank
+/// message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    Korean,
+    Portuguese,
+}
+
+/// Localized lines for non-English locales, keyed by (locale, kill context).
+/// Anything not listed here falls back to the English line in
+/// [`format_kill_message_english`].
+fn localized_kill_message(locale: Locale, kill_context: KillContext, killer: &str, victim: &str) -> Option<String> {
+    match (locale, kill_context) {
+        (Locale::Korean, KillContext::Single) => Some(format!("{killer}가 {victim}을(를) 처치했습니다")),
+        (Locale::Korean, KillContext::Penta) => Some(format!("{killer}의 펜타킬! ({victim})")),
+        (Locale::Portuguese, KillContext::Single) => Some(format!("{killer} matou {victim}")),
+        (Locale::Portuguese, KillContext::Penta) => Some(format!("{killer} conseguiu um PENTAKILL em {victim}!!!!")),
+        _ => None,
+    }
+}
+
+fn format_kill_message_english(context: &KillMessageContext, kill_context: KillContext) -> String {
+    let KillMessageContext { killer, victim } = context;
+    match kill_context {
+        KillContext::Single => format!("{killer} killed {victim}"),
+        KillContext::Double => format!("{killer} got a DOUBLE KILL on {victim}!"),
+        KillContext::Triple => format!("{killer} got a TRIPLE KILL on {victim}!!"),
+        KillContext::Quadra => format!("{killer} got a QUADRA KILL on {victim}!!!"),
+        KillContext::Penta => format!("{killer} got a PENTAKILL on {victim}!!!!"),
+    }
+}
+
+/// Render the text a kill callout message would contain, in `locale` if the
+/// catalog has a line for it, otherwise falling back to English. Shared by
+/// [`post_kill`] (which sends it) and [`preview_discord_message`] (which
+/// doesn't), so the two can never drift.
+fn format_kill_message(context: &KillMessageContext, kill_context: KillContext, locale: Locale) -> String {
+    localized_kill_message(locale, kill_context, &context.killer, &context.victim)
+        .unwrap_or_else(|| format_kill_message_english(context, kill_context))
+}
+
+/// Post a kill callout to the Discord text channel via `send`, localized to `locale`.
+pub fn post_kill(
+    context: &KillMessageContext,
+    kill_context: KillContext,
+    locale: Locale,
+    send: impl FnOnce(&str),
+) {
+    send(&format_kill_message(context, kill_context, locale));
+}
+
+/// Preview the message [`post_kill`] would send for the same inputs,
+/// without sending it — lets users see the formatted text before enabling
+/// text posting.
+pub fn preview_discord_message(context: &KillMessageContext, kill_context: KillContext, locale: Locale) -> String {
+    format_kill_message(context, kill_context, locale)
+}
+
+/// How to render a [`Team`] in a message: a friendly "is it mine" label,
+/// or the actual in-game side's color name, for users who'd rather read
+/// messages the same way the client's scoreboard does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TeamLabelStyle {
+    #[default]
+    Friendly,
+    ColorName,
+}
+
+/// Human-readable label for `team`, relative to the local player, in
+/// `style`. `followed` is only consulted for [`TeamLabelStyle::ColorName`],
+/// to translate the relative [`Team::Ally`]/[`Team::Enemy`] into the
+/// absolute side it corresponds to.
+fn team_label(team: Team, style: TeamLabelStyle, followed: LiveClientTeam) -> &'static str {
+    match style {
+        TeamLabelStyle::Friendly => match team {
+            Team::Ally => "Your team",
+            Team::Enemy => "Enemy team",
+        },
+        TeamLabelStyle::ColorName => {
+            let absolute = match team {
+                Team::Ally => followed,
+                Team::Enemy => followed.opposite(),
+            };
+            match absolute {
+                LiveClientTeam::Order => "Blue Team",
+                LiveClientTeam::Chaos => "Red Team",
+            }
+        }
+    }
+}
+
+fn format_objective_message(objective: Objective, team: Team, style: TeamLabelStyle, followed: LiveClientTeam) -> String {
+    format!("{} secured {objective:?}", team_label(team, style, followed))
+}
+
+/// Post an objective-secured callout to the Discord text channel via
+/// `send`, labeling `team` per `style`. Shares its rendering with
+/// [`preview_objective_message`] so the two can never drift.
+pub fn post_objective(
+    objective: Objective,
+    team: Team,
+    style: TeamLabelStyle,
+    followed: LiveClientTeam,
+    send: impl FnOnce(&str),
+) {
+    send(&format_objective_message(objective, team, style, followed));
+}
+
+/// Preview the message [`post_objective`] would send for the same inputs,
+/// without sending it.
+pub fn preview_objective_message(objective: Objective, team: Team, style: TeamLabelStyle, followed: LiveClientTeam) -> String {
+    format_objective_message(objective, team, style, followed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn context() -> KillMessageContext {
+        KillMessageContext { killer: "Faker".into(), victim: "Chovy".into() }
+    }
+
+    #[test]
+    fn preview_matches_what_post_kill_would_send() {
+        let sent = RefCell::new(None);
+        post_kill(&context(), KillContext::Triple, Locale::English, |msg| {
+            *sent.borrow_mut() = Some(msg.to_string())
+        });
+
+        let preview = preview_discord_message(&context(), KillContext::Triple, Locale::English);
+        assert_eq!(sent.into_inner(), Some(preview));
+    }
+
+    #[test]
+    fn preview_reflects_the_multikill_context() {
+        assert_eq!(
+            preview_discord_message(&context(), KillContext::Single, Locale::English),
+            "Faker killed Chovy"
+        );
+        assert_eq!(
+            preview_discord_message(&context(), KillContext::Penta, Locale::English),
+            "Faker got a PENTAKILL on Chovy!!!!"
+        );
+    }
+
+    #[test]
+    fn switching_locale_changes_the_rendered_message() {
+        let english = preview_discord_message(&context(), KillContext::Single, Locale::English);
+        let korean = preview_discord_message(&context(), KillContext::Single, Locale::Korean);
+        let portuguese = preview_discord_message(&context(), KillContext::Single, Locale::Portuguese);
+
+        assert_eq!(english, "Faker killed Chovy");
+        assert_eq!(korean, "Faker가 Chovy을(를) 처치했습니다");
+        assert_eq!(portuguese, "Faker matou Chovy");
+    }
+
+    #[test]
+    fn missing_catalog_entries_fall_back_to_english() {
+        assert_eq!(
+            preview_discord_message(&context(), KillContext::Double, Locale::Korean),
+            "Faker got a DOUBLE KILL on Chovy!"
+        );
+    }
+
+    #[test]
+    fn friendly_labels_are_relative_to_the_local_player() {
+        assert_eq!(team_label(Team::Ally, TeamLabelStyle::Friendly, LiveClientTeam::Order), "Your team");
+        assert_eq!(team_label(Team::Enemy, TeamLabelStyle::Friendly, LiveClientTeam::Order), "Enemy team");
+    }
+
+    #[test]
+    fn color_name_labels_follow_the_absolute_side() {
+        assert_eq!(team_label(Team::Ally, TeamLabelStyle::ColorName, LiveClientTeam::Order), "Blue Team");
+        assert_eq!(team_label(Team::Enemy, TeamLabelStyle::ColorName, LiveClientTeam::Order), "Red Team");
+    }
+
+    #[test]
+    fn color_name_labels_flip_with_the_followed_team() {
+        assert_eq!(team_label(Team::Ally, TeamLabelStyle::ColorName, LiveClientTeam::Chaos), "Red Team");
+        assert_eq!(team_label(Team::Enemy, TeamLabelStyle::ColorName, LiveClientTeam::Chaos), "Blue Team");
+    }
+
+    #[test]
+    fn preview_objective_matches_what_post_objective_would_send() {
+        let sent = RefCell::new(None);
+        post_objective(Objective::Baron, Team::Ally, TeamLabelStyle::Friendly, LiveClientTeam::Order, |msg| {
+            *sent.borrow_mut() = Some(msg.to_string())
+        });
+
+        let preview = preview_objective_message(Objective::Baron, Team::Ally, TeamLabelStyle::Friendly, LiveClientTeam::Order);
+        assert_eq!(sent.into_inner(), Some(preview));
+    }
+
+    #[test]
+    fn objective_message_applies_the_configured_label_style() {
+        assert_eq!(
+            preview_objective_message(Objective::Baron, Team::Ally, TeamLabelStyle::Friendly, LiveClientTeam::Order),
+            "Your team secured Baron"
+        );
+        assert_eq!(
+            preview_objective_message(Objective::Dragon, Team::Enemy, TeamLabelStyle::ColorName, LiveClientTeam::Order),
+            "Red Team secured Dragon"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+pub mod backend;
+pub mod channel;
+pub mod empty_channel;
+pub mod events;
+pub mod heartbeat;
+pub mod message;
+pub mod presence;
+pub mod reactions;
+pub mod sync;
+pub mod voice;
+
+use crate::sound::pack::PackError;
+use crate::sound::SoundPack;
+
+pub use backend::{test_event_roundtrip, Backend, BackendConfig, DiscordApiError, RoundtripResult, RoundtripTiming};
+pub use channel::{
+    list_usable_channels, resolve_channel_by_name, validate_channel_in_guilds, ChannelLookupError,
+    Guild, VoiceChannel,
+};
+pub use empty_channel::{EmptyChannelAction, EmptyChannelBehavior};
+pub use events::ClientEvent;
+pub use heartbeat::Heartbeat;
+pub use message::{
+    post_kill, post_objective, preview_discord_message, preview_objective_message, KillMessageContext, Locale,
+    TeamLabelStyle,
+};
+pub use presence::{ActivityKind, Presence};
+pub use reactions::{AnnouncementStyles, EventAnnouncement};
+pub use sync::TeamSyncCoordinator;
+pub use voice::{VoiceHandle, VoiceInitError};
+
+/// Holds the live voice connection plus the sound packs currently in use.
+/// `sound_pack` drives simple event->sound playback; `custom_rules_pack`
+/// (if set) backs the rules engine. `songbird` is `None` whenever voice
+/// init failed (or hasn't been attempted yet) — playback should go through
+/// [`Self::ensure_voice`] rather than unwrapping it directly.
+pub struct DiscordClient {
+    pub sound_pack: SoundPack,
+    pub custom_rules_pack: Option<SoundPack>,
+    pub songbird: Option<VoiceHandle>,
+    events: Vec<ClientEvent>,
+}
+
+impl DiscordClient {
+    pub fn new(sound_pack: SoundPack, custom_rules_pack: Option<SoundPack>) -> Self {
+        DiscordClient {
+            sound_pack,
+            custom_rules_pack,
+            songbird: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Retry initializing the voice connection, e.g. in response to a user
+    /// command, without touching anything else about the client's state.
+    pub fn retry_voice_init(
+        &mut self,
+        init: impl FnOnce() -> Result<VoiceHandle, VoiceInitError>,
+    ) -> Result<(), VoiceInitError> {
+        let handle = init()?;
+        self.songbird = Some(handle);
+        Ok(())
+    }
+
+    /// Get the current voice handle, auto-retrying initialization once if
+    /// it isn't set yet. Playback call sites should go through this instead
+    /// of reading `songbird` directly, so a transient init failure at
+    /// startup doesn't permanently disable voice.
+    pub fn ensure_voice(
+        &mut self,
+        init: impl FnOnce() -> Result<VoiceHandle, VoiceInitError>,
+    ) -> Result<&VoiceHandle, VoiceInitError> {
+        if self.songbird.is_none() {
+            self.retry_voice_init(init)?;
+        }
+        Ok(self.songbird.as_ref().expect("just set above"))
+    }
+
+    /// Re-read `sound-pack.json` (and the custom rules pack, if any) from
+    /// disk, validate them, and swap them into place without dropping the
+    /// voice connection. Leaves the previous packs in place on failure.
+    pub fn reload_sound_pack(&mut self) -> Result<(), PackError> {
+        let new_sound_pack = SoundPack::load(&self.sound_pack.path);
+        let new_rules_pack = self.custom_rules_pack.as_ref().map(|pack| SoundPack::load(&pack.path));
+
+        match (new_sound_pack, new_rules_pack) {
+            (Ok(sound_pack), None) => {
+                self.sound_pack = sound_pack;
+                self.events.push(ClientEvent::SoundPackReloaded { ok: true });
+                Ok(())
+            }
+            (Ok(sound_pack), Some(Ok(rules_pack))) => {
+                self.sound_pack = sound_pack;
+                self.custom_rules_pack = Some(rules_pack);
+                self.events.push(ClientEvent::SoundPackReloaded { ok: true });
+                Ok(())
+            }
+            (Err(e), _) => {
+                self.events.push(ClientEvent::SoundPackReloaded { ok: false });
+                Err(e)
+            }
+            (_, Some(Err(e))) => {
+                self.events.push(ClientEvent::SoundPackReloaded { ok: false });
+                Err(e)
+            }
+        }
+    }
+
+    pub fn drain_events(&mut self) -> Vec<ClientEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_pack(path: &std::path::Path, id: &str) {
+        let json = format!(
+            r#"{{"id": "{id}", "pools": {{"kill": {{"mode": "random", "sounds": [{{"id": "a"}}]}}}}}}"#
+        );
+        let mut f = std::fs::File::create(path).unwrap();
+        f.write_all(json.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn reload_replaces_the_in_memory_pack() {
+        let dir = std::env::temp_dir().join(format!("scout-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sound-pack.json");
+        write_pack(&path, "original");
+
+        let pack = SoundPack::load(&path).unwrap();
+        let mut client = DiscordClient::new(pack, None);
+        assert_eq!(client.sound_pack.id, "original");
+
+        write_pack(&path, "updated");
+        client.reload_sound_pack().unwrap();
+
+        assert_eq!(client.sound_pack.id, "updated");
+        assert_eq!(
+            client.drain_events(),
+            vec![ClientEvent::SoundPackReloaded { ok: true }]
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
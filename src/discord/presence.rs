@@ -0,0 +1,52 @@
+/// The kind of activity shown alongside the bot's presence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    Playing,
+    Listening,
+    Watching,
+}
+
+/// The bot's configured Discord presence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Presence {
+    pub kind: ActivityKind,
+    pub text: String,
+}
+
+impl Default for Presence {
+    fn default() -> Self {
+        Presence {
+            kind: ActivityKind::Watching,
+            text: "the Rift".to_string(),
+        }
+    }
+}
+
+/// Pick the presence to show given whether a game is currently being
+/// monitored (see [`crate::game::GameState::is_monitoring`]) — distinct text
+/// so idle bots don't look like they're watching a game that already ended.
+pub fn select_presence(is_monitoring: bool) -> Presence {
+    if is_monitoring {
+        Presence { kind: ActivityKind::Watching, text: "a LoL game".to_string() }
+    } else {
+        Presence::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monitoring_a_game_selects_in_game_text() {
+        assert_eq!(
+            select_presence(true),
+            Presence { kind: ActivityKind::Watching, text: "a LoL game".to_string() }
+        );
+    }
+
+    #[test]
+    fn not_monitoring_falls_back_to_the_default_presence() {
+        assert_eq!(select_presence(false), Presence::default());
+    }
+}
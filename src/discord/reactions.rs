@@ -0,0 +1,58 @@
+/// How an event should be surfaced in the Discord text channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventAnnouncement {
+    /// Post a standalone message.
+    Message(String),
+    /// React to the existing "game in progress" message with an emoji,
+    /// for events too frequent to warrant their own message (e.g. pings).
+    Reaction(String),
+}
+
+/// Per-event-kind config for which style of announcement to use.
+#[derive(Debug, Clone, Default)]
+pub struct AnnouncementStyles {
+    reaction_emojis: std::collections::HashMap<String, String>,
+}
+
+impl AnnouncementStyles {
+    pub fn new() -> Self {
+        AnnouncementStyles::default()
+    }
+
+    pub fn use_reaction(&mut self, event_kind: impl Into<String>, emoji: impl Into<String>) {
+        self.reaction_emojis.insert(event_kind.into(), emoji.into());
+    }
+
+    /// Decide how to announce `event_kind`, falling back to `message` if no
+    /// reaction style is configured for it.
+    pub fn announce(&self, event_kind: &str, message: String) -> EventAnnouncement {
+        match self.reaction_emojis.get(event_kind) {
+            Some(emoji) => EventAnnouncement::Reaction(emoji.clone()),
+            None => EventAnnouncement::Message(message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_a_message() {
+        let styles = AnnouncementStyles::new();
+        assert_eq!(
+            styles.announce("ping", "Danger ping!".into()),
+            EventAnnouncement::Message("Danger ping!".into())
+        );
+    }
+
+    #[test]
+    fn configured_events_react_instead() {
+        let mut styles = AnnouncementStyles::new();
+        styles.use_reaction("ping", "⚠️");
+        assert_eq!(
+            styles.announce("ping", "Danger ping!".into()),
+            EventAnnouncement::Reaction("⚠️".into())
+        );
+    }
+}
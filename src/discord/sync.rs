@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long a client's last heartbeat stays valid before it's treated as
+/// gone (e.g. after losing connection without a clean disconnect) and drops
+/// out of primary election.
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+struct RegisteredClient {
+    voice_channel_id: String,
+    last_heartbeat_at: Instant,
+}
+
+/// Coordinates which of a team's scout-for-lol instances is the "primary"
+/// for a shared voice channel, so premade teammates don't all play the same
+/// sound at once. Clients register themselves via [`Self::register_heartbeat`]
+/// as they heartbeat (see [`super::heartbeat`]), and [`Self::primary_for_channel`]
+/// runs the same deterministic election over that registered set on every
+/// client, so they all agree on a primary without a central backend.
+#[derive(Debug, Default)]
+pub struct TeamSyncCoordinator {
+    clients: HashMap<String, RegisteredClient>,
+}
+
+impl TeamSyncCoordinator {
+    pub fn new() -> Self {
+        TeamSyncCoordinator::default()
+    }
+
+    /// Record that `client_id` is alive and in `voice_channel_id` as of
+    /// `now`, overwriting any previous registration for that client
+    /// (e.g. if it switched channels).
+    pub fn register_heartbeat(&mut self, client_id: &str, voice_channel_id: &str, now: Instant) {
+        self.clients.insert(
+            client_id.to_string(),
+            RegisteredClient { voice_channel_id: voice_channel_id.to_string(), last_heartbeat_at: now },
+        );
+    }
+
+    /// Drop a client's registration outright (e.g. on clean shutdown),
+    /// rather than waiting for its heartbeat to time out.
+    pub fn forget(&mut self, client_id: &str) {
+        self.clients.remove(client_id);
+    }
+
+    /// Elect the primary for `voice_channel_id`: the lexicographically
+    /// smallest client id among clients registered to that channel whose
+    /// last heartbeat is still within [`HEARTBEAT_TIMEOUT`] of `now`. Picking
+    /// the smallest id is arbitrary but deterministic, which is all that
+    /// matters here — every client runs this same election independently
+    /// and needs to land on the same answer. `None` if no live client is
+    /// registered to that channel.
+    pub fn primary_for_channel(&self, voice_channel_id: &str, now: Instant) -> Option<&str> {
+        self.clients
+            .iter()
+            .filter(|(_, c)| {
+                c.voice_channel_id == voice_channel_id
+                    && now.saturating_duration_since(c.last_heartbeat_at) < HEARTBEAT_TIMEOUT
+            })
+            .map(|(id, _)| id.as_str())
+            .min()
+    }
+
+    /// Whether `client_id` is currently the elected primary for
+    /// `voice_channel_id` — the check a client makes before playing a
+    /// shared event's sound.
+    pub fn is_primary(&self, client_id: &str, voice_channel_id: &str, now: Instant) -> bool {
+        self.primary_for_channel(voice_channel_id, now) == Some(client_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_smallest_client_id_in_a_channel_is_elected_primary() {
+        let mut sync = TeamSyncCoordinator::new();
+        let now = Instant::now();
+        sync.register_heartbeat("client-b", "voice-1", now);
+        sync.register_heartbeat("client-a", "voice-1", now);
+        sync.register_heartbeat("client-c", "voice-1", now);
+
+        assert_eq!(sync.primary_for_channel("voice-1", now), Some("client-a"));
+        assert!(sync.is_primary("client-a", "voice-1", now));
+        assert!(!sync.is_primary("client-b", "voice-1", now));
+    }
+
+    #[test]
+    fn election_is_independent_per_voice_channel() {
+        let mut sync = TeamSyncCoordinator::new();
+        let now = Instant::now();
+        sync.register_heartbeat("client-a", "voice-1", now);
+        sync.register_heartbeat("client-z", "voice-2", now);
+
+        assert_eq!(sync.primary_for_channel("voice-1", now), Some("client-a"));
+        assert_eq!(sync.primary_for_channel("voice-2", now), Some("client-z"));
+    }
+
+    #[test]
+    fn a_client_whose_heartbeat_has_timed_out_drops_out_of_election() {
+        let mut sync = TeamSyncCoordinator::new();
+        let now = Instant::now();
+        sync.register_heartbeat("client-a", "voice-1", now);
+        sync.register_heartbeat("client-b", "voice-1", now);
+
+        let later = now + HEARTBEAT_TIMEOUT + Duration::from_secs(1);
+        sync.register_heartbeat("client-b", "voice-1", later);
+
+        assert_eq!(sync.primary_for_channel("voice-1", later), Some("client-b"));
+    }
+
+    #[test]
+    fn forgetting_a_client_removes_it_from_election() {
+        let mut sync = TeamSyncCoordinator::new();
+        let now = Instant::now();
+        sync.register_heartbeat("client-a", "voice-1", now);
+        sync.register_heartbeat("client-b", "voice-1", now);
+
+        sync.forget("client-a");
+
+        assert_eq!(sync.primary_for_channel("voice-1", now), Some("client-b"));
+    }
+
+    #[test]
+    fn no_registered_clients_means_no_primary() {
+        let sync = TeamSyncCoordinator::new();
+        assert_eq!(sync.primary_for_channel("voice-1", Instant::now()), None);
+    }
+}
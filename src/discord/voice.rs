@@ -0,0 +1,66 @@
+/// A connected voice session. Opaque from this crate's point of view — the
+/// actual songbird driver lives on the other side of whatever `init`
+/// closure callers pass to [`super::DiscordClient::retry_voice_init`].
+pub struct VoiceHandle {
+    pub channel_id: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("voice manager not initialized: {0}")]
+pub struct VoiceInitError(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::super::DiscordClient;
+    use super::*;
+    use crate::sound::SoundPack;
+
+    fn client(name: &str) -> DiscordClient {
+        let dir = std::env::temp_dir().join(format!("scout-voice-test-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sound-pack.json");
+        std::fs::write(
+            &path,
+            r#"{"id": "p", "pools": {"kill": {"mode": "random", "sounds": [{"id": "a"}]}}}"#,
+        )
+        .unwrap();
+        DiscordClient::new(SoundPack::load(&path).unwrap(), None)
+    }
+
+    #[test]
+    fn retry_populates_songbird_on_success() {
+        let mut client = client("retry_populates_songbird_on_success");
+        assert!(client.songbird.is_none());
+
+        client
+            .retry_voice_init(|| Ok(VoiceHandle { channel_id: 42 }))
+            .unwrap();
+
+        assert!(client.songbird.is_some());
+        assert_eq!(client.songbird.as_ref().unwrap().channel_id, 42);
+    }
+
+    #[test]
+    fn retry_leaves_songbird_none_on_failure() {
+        let mut client = client("retry_leaves_songbird_none_on_failure");
+
+        let err = client
+            .retry_voice_init(|| Err(VoiceInitError("no driver".into())))
+            .unwrap_err();
+
+        assert_eq!(err.0, "no driver");
+        assert!(client.songbird.is_none());
+    }
+
+    #[test]
+    fn ensure_voice_auto_retries_once_when_uninitialized() {
+        let mut client = client("ensure_voice_auto_retries_once_when_uninitialized");
+
+        let handle = client
+            .ensure_voice(|| Ok(VoiceHandle { channel_id: 7 }))
+            .unwrap();
+
+        assert_eq!(handle.channel_id, 7);
+        assert!(client.songbird.is_some());
+    }
+}
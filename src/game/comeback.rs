@@ -0,0 +1,97 @@
+use super::GameEvent;
+
+/// How far behind the local team has to be for a won fight to count as a
+/// comeback. Either threshold being met is enough — a team can be crushed
+/// on gold while even on kills, or vice versa.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComebackThresholds {
+    pub minimum_gold_deficit: i64,
+    pub minimum_kill_deficit: i32,
+}
+
+impl Default for ComebackThresholds {
+    fn default() -> Self {
+        ComebackThresholds { minimum_gold_deficit: 3000, minimum_kill_deficit: 3 }
+    }
+}
+
+/// The local team's standing versus the enemy team just before a fight,
+/// positive meaning ahead.
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize)]
+pub struct TeamDiff {
+    pub gold_diff: i64,
+    pub kill_diff: i32,
+}
+
+/// The outcome of a single teamfight, in kills.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FightOutcome {
+    pub ally_kills: u32,
+    pub enemy_kills: u32,
+}
+
+/// A comeback is a fight the local team wins outright while having entered
+/// it behind on gold or kills by at least the configured threshold.
+pub fn detect_comeback(
+    diff: TeamDiff,
+    outcome: FightOutcome,
+    thresholds: ComebackThresholds,
+) -> Option<GameEvent> {
+    if outcome.ally_kills <= outcome.enemy_kills {
+        return None;
+    }
+
+    let behind_on_gold = diff.gold_diff <= -thresholds.minimum_gold_deficit;
+    let behind_on_kills = diff.kill_diff <= -thresholds.minimum_kill_deficit;
+    if !behind_on_gold && !behind_on_kills {
+        return None;
+    }
+
+    Some(GameEvent::Comeback { gold_deficit: -diff.gold_diff, kill_deficit: -diff.kill_diff })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_comeback_when_the_fight_is_lost() {
+        let diff = TeamDiff { gold_diff: -5000, kill_diff: -5 };
+        let outcome = FightOutcome { ally_kills: 1, enemy_kills: 3 };
+        assert_eq!(detect_comeback(diff, outcome, ComebackThresholds::default()), None);
+    }
+
+    #[test]
+    fn no_comeback_when_the_team_was_ahead() {
+        let diff = TeamDiff { gold_diff: 5000, kill_diff: 5 };
+        let outcome = FightOutcome { ally_kills: 3, enemy_kills: 1 };
+        assert_eq!(detect_comeback(diff, outcome, ComebackThresholds::default()), None);
+    }
+
+    #[test]
+    fn comeback_fires_on_a_gold_deficit_win() {
+        let diff = TeamDiff { gold_diff: -4000, kill_diff: 0 };
+        let outcome = FightOutcome { ally_kills: 3, enemy_kills: 1 };
+        assert_eq!(
+            detect_comeback(diff, outcome, ComebackThresholds::default()),
+            Some(GameEvent::Comeback { gold_deficit: 4000, kill_deficit: 0 })
+        );
+    }
+
+    #[test]
+    fn comeback_fires_on_a_kill_deficit_win_even_with_even_gold() {
+        let diff = TeamDiff { gold_diff: 0, kill_diff: -4 };
+        let outcome = FightOutcome { ally_kills: 2, enemy_kills: 0 };
+        assert_eq!(
+            detect_comeback(diff, outcome, ComebackThresholds::default()),
+            Some(GameEvent::Comeback { gold_deficit: 0, kill_deficit: 4 })
+        );
+    }
+
+    #[test]
+    fn a_narrow_deficit_below_threshold_does_not_count() {
+        let diff = TeamDiff { gold_diff: -500, kill_diff: -1 };
+        let outcome = FightOutcome { ally_kills: 2, enemy_kills: 0 };
+        assert_eq!(detect_comeback(diff, outcome, ComebackThresholds::default()), None);
+    }
+}
@@ -0,0 +1,55 @@
+use super::events::GameEvent;
+
+/// Append-only log of every event seen this game, timestamped, so a
+/// freshly (re)started client can query "what did I miss" instead of
+/// starting blind.
+#[derive(Debug, Default)]
+pub struct EventLog {
+    entries: Vec<(f64, GameEvent)>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        EventLog::default()
+    }
+
+    pub fn record(&mut self, game_time: f64, event: GameEvent) {
+        self.entries.push((game_time, event));
+    }
+
+    /// All events recorded strictly after `game_time`, in order.
+    pub fn events_since(&self, game_time: f64) -> Vec<&GameEvent> {
+        self.entries
+            .iter()
+            .filter(|(t, _)| *t > game_time)
+            .map(|(_, e)| e)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events::PingKind;
+
+    fn ping() -> GameEvent {
+        GameEvent::Ping { kind: PingKind::Danger, from_local_player: true }
+    }
+
+    #[test]
+    fn events_since_excludes_events_at_or_before_the_given_time() {
+        let mut log = EventLog::new();
+        log.record(10.0, ping());
+        log.record(20.0, ping());
+        log.record(30.0, ping());
+
+        assert_eq!(log.events_since(20.0).len(), 1);
+    }
+
+    #[test]
+    fn events_since_returns_everything_for_a_fresh_start() {
+        let mut log = EventLog::new();
+        log.record(10.0, ping());
+        assert_eq!(log.events_since(0.0).len(), 1);
+    }
+}
@@ -0,0 +1,208 @@
+use serde::Deserialize;
+
+use super::kills::Team;
+use super::objectives::Objective;
+
+/// The map pings League exposes, reused here as a cue trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum PingKind {
+    Danger,
+    OnMyWay,
+    Assistance,
+    Retreat,
+    Missing,
+}
+
+/// Events derived from the game, either forwarded directly from the Live
+/// Client API or synthesized locally (e.g. from timers).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum GameEvent {
+    /// An objective is about to or has become available to contest.
+    ObjectiveSpawn { objective: Objective, game_time: f64 },
+    /// The local player (or a teammate, for team pings we can see) pinged.
+    Ping { kind: PingKind, from_local_player: bool },
+    /// A surrender vote was called; `votes_for`/`votes_needed` let rules
+    /// react differently to a close vote vs. a landslide.
+    SurrenderVoteCalled { votes_for: u32, votes_needed: u32 },
+    /// An objective was finished off with a smite while contested — a
+    /// "steal" if `stolen_by_enemy` is true, otherwise a clean secure.
+    /// `contesting_team`/`was_close` add flavor for richer rules/messages,
+    /// where the Live Client/LCU surfaces that context (see
+    /// [`parse_objective_smited`]) — not every poll will have it.
+    ObjectiveSmited {
+        objective: Objective,
+        stolen_by_enemy: bool,
+        contesting_team: Option<Team>,
+        was_close: bool,
+    },
+    /// An objective was secured by `team` — rules typically pair `Enemy`
+    /// with a distinct warning tone so it reads as a threat, not hype.
+    ObjectiveTaken { objective: Objective, team: Team },
+    /// The local team won a fight despite trailing on gold and/or kills
+    /// beforehand, per [`super::comeback::detect_comeback`]. The deficits
+    /// are carried along so rules/announcements can react to how big the
+    /// comeback was.
+    Comeback { gold_deficit: i64, kill_deficit: i32 },
+    /// The active player's item set gained a new completed item since the
+    /// last poll (a mythic/legendary spike, typically).
+    ItemComplete { item_name: String },
+}
+
+/// Raw shape of an LCU ping payload, kept separate from [`GameEvent`] so
+/// changes to the wire format don't ripple into rule matching. Subscribed
+/// to over the LCU event socket, once that transport exists.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PingPayload {
+    pub ping_category: String,
+    pub is_local_player: bool,
+}
+
+/// Map a raw LCU ping payload to a [`GameEvent::Ping`], or `None` if
+/// `ping_category` isn't one we recognize.
+pub fn map_ping_payload(payload: &PingPayload) -> Option<GameEvent> {
+    let kind = match payload.ping_category.as_str() {
+        "danger" => PingKind::Danger,
+        "onMyWay" => PingKind::OnMyWay,
+        "assist" => PingKind::Assistance,
+        "retreat" => PingKind::Retreat,
+        "missing" => PingKind::Missing,
+        _ => return None,
+    };
+    Some(GameEvent::Ping { kind, from_local_player: payload.is_local_player })
+}
+
+/// Raw shape of an LCU surrender-vote payload.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SurrenderVotePayload {
+    pub votes_for: u32,
+    pub votes_needed: u32,
+}
+
+/// Map a raw LCU surrender-vote payload to a [`GameEvent::SurrenderVoteCalled`].
+pub fn map_surrender_vote_payload(payload: &SurrenderVotePayload) -> GameEvent {
+    GameEvent::SurrenderVoteCalled {
+        votes_for: payload.votes_for,
+        votes_needed: payload.votes_needed,
+    }
+}
+
+/// Raw contest context for an `ObjectiveSmited` event, as much as the Live
+/// Client/LCU surfaces it — not every field is always available.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SmiteContext {
+    pub contesting_team: Option<Team>,
+    pub contest_duration_ms: Option<u64>,
+}
+
+/// Below this contest duration, a steal reads as "close" rather than a
+/// clean, uncontested secure.
+const CLOSE_STEAL_THRESHOLD_MS: u64 = 3_000;
+
+/// Build a [`GameEvent::ObjectiveSmited`] from the base steal fact plus
+/// whatever contest context is available.
+pub fn parse_objective_smited(objective: Objective, stolen_by_enemy: bool, context: SmiteContext) -> GameEvent {
+    GameEvent::ObjectiveSmited {
+        objective,
+        stolen_by_enemy,
+        contesting_team: context.contesting_team,
+        was_close: context.contest_duration_ms.is_some_and(|ms| ms < CLOSE_STEAL_THRESHOLD_MS),
+    }
+}
+
+impl GameEvent {
+    /// Stable, human-readable name for this event's variant, independent of
+    /// its payload. Used anywhere events need to be listed or keyed by
+    /// type, e.g. the event-to-sound binding list.
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            GameEvent::ObjectiveSpawn { .. } => "objective_spawn",
+            GameEvent::Ping { .. } => "ping",
+            GameEvent::SurrenderVoteCalled { .. } => "surrender_vote_called",
+            GameEvent::ObjectiveSmited { .. } => "objective_smited",
+            GameEvent::ObjectiveTaken { team: Team::Ally, .. } => "objective_taken_ally",
+            GameEvent::ObjectiveTaken { team: Team::Enemy, .. } => "objective_taken_enemy",
+            GameEvent::Comeback { .. } => "comeback",
+            GameEvent::ItemComplete { .. } => "item_complete",
+        }
+    }
+
+    /// Every [`Self::kind_name`] that can occur, for callers that need to
+    /// enumerate all event kinds rather than just the ones seen so far
+    /// (e.g. binding them all to one sound at once).
+    pub fn all_kind_names() -> &'static [&'static str] {
+        &[
+            "objective_spawn",
+            "ping",
+            "surrender_vote_called",
+            "objective_smited",
+            "objective_taken_ally",
+            "objective_taken_enemy",
+            "comeback",
+            "item_complete",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_kind_names_has_no_duplicates() {
+        let names = GameEvent::all_kind_names();
+        let unique: std::collections::HashSet<_> = names.iter().collect();
+        assert_eq!(unique.len(), names.len());
+    }
+
+    #[test]
+    fn a_sample_ping_payload_routes_to_the_ping_event() {
+        let payload = PingPayload { ping_category: "danger".to_string(), is_local_player: true };
+        assert_eq!(
+            map_ping_payload(&payload),
+            Some(GameEvent::Ping { kind: PingKind::Danger, from_local_player: true })
+        );
+    }
+
+    #[test]
+    fn an_unrecognized_ping_category_is_not_routed() {
+        let payload = PingPayload { ping_category: "nonsense".to_string(), is_local_player: true };
+        assert_eq!(map_ping_payload(&payload), None);
+    }
+
+    #[test]
+    fn a_sample_surrender_vote_payload_routes_to_the_surrender_vote_event() {
+        let payload = SurrenderVotePayload { votes_for: 3, votes_needed: 5 };
+        assert_eq!(
+            map_surrender_vote_payload(&payload),
+            GameEvent::SurrenderVoteCalled { votes_for: 3, votes_needed: 5 }
+        );
+    }
+
+    #[test]
+    fn a_quick_steal_parses_as_close() {
+        let context = SmiteContext { contesting_team: Some(Team::Enemy), contest_duration_ms: Some(1_500) };
+        let event = parse_objective_smited(Objective::Baron, true, context);
+        assert_eq!(
+            event,
+            GameEvent::ObjectiveSmited {
+                objective: Objective::Baron,
+                stolen_by_enemy: true,
+                contesting_team: Some(Team::Enemy),
+                was_close: true,
+            }
+        );
+    }
+
+    #[test]
+    fn a_long_contest_does_not_parse_as_close() {
+        let context = SmiteContext { contesting_team: Some(Team::Ally), contest_duration_ms: Some(10_000) };
+        let event = parse_objective_smited(Objective::Dragon, false, context);
+        assert!(matches!(event, GameEvent::ObjectiveSmited { was_close: false, .. }));
+    }
+
+    #[test]
+    fn missing_contest_duration_does_not_parse_as_close() {
+        let event = parse_objective_smited(Objective::Baron, false, SmiteContext::default());
+        assert!(matches!(event, GameEvent::ObjectiveSmited { was_close: false, contesting_team: None, .. }));
+    }
+}
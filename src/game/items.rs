@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use super::events::GameEvent;
+
+/// Tracks the active player's completed items across Live Client API polls,
+/// emitting a [`GameEvent::ItemComplete`] for each newly-seen item.
+///
+/// "Completed" is whatever the caller passes in as the polled item list —
+/// typically the Live Client API's `completedItem: true` entries, since
+/// components and unfinished items don't warrant a callout.
+#[derive(Debug, Default)]
+pub struct ItemTracker {
+    seen: HashSet<String>,
+}
+
+impl ItemTracker {
+    /// Diff `completed_items` against everything seen on a previous poll,
+    /// returning one [`GameEvent::ItemComplete`] per item not seen before.
+    pub fn poll(&mut self, completed_items: &[String]) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+
+        for item_name in completed_items {
+            if self.seen.insert(item_name.clone()) {
+                events.push(GameEvent::ItemComplete { item_name: item_name.clone() });
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_poll_announces_every_completed_item() {
+        let mut tracker = ItemTracker::default();
+        let events = tracker.poll(&["Kraken Slayer".to_string(), "Mercury's Treads".to_string()]);
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn an_unchanged_item_set_fires_nothing() {
+        let mut tracker = ItemTracker::default();
+        tracker.poll(&["Kraken Slayer".to_string()]);
+        assert!(tracker.poll(&["Kraken Slayer".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn a_newly_completed_item_fires_exactly_once() {
+        let mut tracker = ItemTracker::default();
+        tracker.poll(&["Kraken Slayer".to_string()]);
+        let events = tracker.poll(&["Kraken Slayer".to_string(), "Infinity Edge".to_string()]);
+        assert_eq!(
+            events,
+            vec![GameEvent::ItemComplete { item_name: "Infinity Edge".to_string() }]
+        );
+    }
+
+    #[test]
+    fn selling_an_item_does_not_re_announce_it_if_rebought() {
+        let mut tracker = ItemTracker::default();
+        tracker.poll(&["Kraken Slayer".to_string()]);
+        let events = tracker.poll(&[]);
+        assert!(events.is_empty());
+        let events = tracker.poll(&["Kraken Slayer".to_string()]);
+        assert!(events.is_empty());
+    }
+}
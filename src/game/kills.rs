@@ -0,0 +1,236 @@
+/// How many kills the local player has landed in quick succession, the
+/// classic League "multikill" ladder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillContext {
+    Single,
+    Double,
+    Triple,
+    Quadra,
+    Penta,
+}
+
+impl KillContext {
+    fn from_count(count: u32) -> Self {
+        match count {
+            0 | 1 => KillContext::Single,
+            2 => KillContext::Double,
+            3 => KillContext::Triple,
+            4 => KillContext::Quadra,
+            _ => KillContext::Penta,
+        }
+    }
+
+    /// Number of kills this context represents, the inverse of [`Self::from_count`].
+    pub fn kill_count(self) -> u32 {
+        match self {
+            KillContext::Single => 1,
+            KillContext::Double => 2,
+            KillContext::Triple => 3,
+            KillContext::Quadra => 4,
+            KillContext::Penta => 5,
+        }
+    }
+
+    /// Whether this context should trigger a sound, given the user's
+    /// configured minimum multikill threshold (e.g. set to 2 to skip
+    /// single-kill callouts and only hype multikills).
+    pub fn meets_threshold(self, minimum: KillContext) -> bool {
+        self.kill_count() >= minimum.kill_count()
+    }
+}
+
+/// Which side of the game a multikill happened on, relative to the local
+/// player, so rules can react differently (hype vs. warning tone).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+pub enum Team {
+    Ally,
+    Enemy,
+}
+
+/// A classified multikill plus who it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultikillEvent {
+    pub team: Team,
+    pub context: KillContext,
+}
+
+/// League resets a multikill window if 10s pass without another kill.
+const MULTIKILL_WINDOW_SECONDS: f64 = 10.0;
+
+/// Tracks the local player's kill timestamps to classify each new kill by
+/// its multikill context.
+#[derive(Debug, Default)]
+pub struct KillTracker {
+    last_kill_time: Option<f64>,
+    streak: u32,
+    total_kills: u32,
+}
+
+impl KillTracker {
+    pub fn new() -> Self {
+        KillTracker::default()
+    }
+
+    /// Record a kill at `game_time`, returning its [`KillContext`] and
+    /// whether it's this tracker's very first kill of the game (distinct
+    /// from a multikill context, and worth its own standout sound).
+    pub fn record_kill(&mut self, game_time: f64) -> (KillContext, bool) {
+        let within_window = self
+            .last_kill_time
+            .is_some_and(|t| game_time - t <= MULTIKILL_WINDOW_SECONDS);
+
+        self.streak = if within_window { self.streak + 1 } else { 1 };
+        self.last_kill_time = Some(game_time);
+        self.total_kills += 1;
+        (KillContext::from_count(self.streak), self.total_kills == 1)
+    }
+
+    /// How many kills the local player has landed so far this game.
+    pub fn total_kills(&self) -> u32 {
+        self.total_kills
+    }
+}
+
+/// Tracks multikill streaks per player so ally and enemy multikills can be
+/// classified independently of one another.
+#[derive(Debug, Default)]
+pub struct TeamMultikillTracker {
+    trackers: std::collections::HashMap<String, (Team, KillTracker)>,
+}
+
+impl TeamMultikillTracker {
+    pub fn new() -> Self {
+        TeamMultikillTracker::default()
+    }
+
+    /// Record a kill by `player_id` (on `team`) at `game_time`.
+    pub fn record_kill(&mut self, player_id: &str, team: Team, game_time: f64) -> MultikillEvent {
+        let (_, tracker) = self
+            .trackers
+            .entry(player_id.to_string())
+            .or_insert_with(|| (team, KillTracker::new()));
+        let (context, _) = tracker.record_kill(game_time);
+        MultikillEvent { team, context }
+    }
+}
+
+/// How close in game-time a `Multikill` event has to land to a plain
+/// `ChampionKill` event for the same killer to be considered a duplicate
+/// signal for the same slaying moment rather than two distinct kills.
+const DUPLICATE_KILL_WINDOW_SECONDS: f64 = 1.0;
+
+/// Live Client sometimes emits both `ChampionKill` and `Multikill` for the
+/// same slaying moment. Suppresses the plain kill sound when a multikill
+/// event for the same killer landed within [`DUPLICATE_KILL_WINDOW_SECONDS`],
+/// so the two don't play on top of each other.
+#[derive(Debug, Default)]
+pub struct DuplicateKillSuppressor {
+    last_multikill_time: std::collections::HashMap<String, f64>,
+}
+
+impl DuplicateKillSuppressor {
+    pub fn new() -> Self {
+        DuplicateKillSuppressor::default()
+    }
+
+    /// Record that `killer` landed a `Multikill` event at `game_time`.
+    pub fn record_multikill(&mut self, killer: &str, game_time: f64) {
+        self.last_multikill_time.insert(killer.to_string(), game_time);
+    }
+
+    /// Whether a plain kill sound for `killer` at `game_time` should be
+    /// suppressed because a multikill event for them landed within the window.
+    pub fn should_suppress_plain_kill(&self, killer: &str, game_time: f64) -> bool {
+        self.last_multikill_time
+            .get(killer)
+            .is_some_and(|&t| (game_time - t).abs() <= DUPLICATE_KILL_WINDOW_SECONDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kills_within_the_window_chain_into_a_multikill() {
+        let mut tracker = KillTracker::new();
+        assert_eq!(tracker.record_kill(0.0).0, KillContext::Single);
+        assert_eq!(tracker.record_kill(5.0).0, KillContext::Double);
+        assert_eq!(tracker.record_kill(12.0).0, KillContext::Triple);
+    }
+
+    #[test]
+    fn a_gap_past_the_window_resets_the_streak() {
+        let mut tracker = KillTracker::new();
+        tracker.record_kill(0.0);
+        tracker.record_kill(5.0);
+        assert_eq!(tracker.record_kill(20.0).0, KillContext::Single);
+    }
+
+    #[test]
+    fn five_chained_kills_is_a_pentakill() {
+        let mut tracker = KillTracker::new();
+        let mut last = KillContext::Single;
+        for t in [0.0, 2.0, 4.0, 6.0, 8.0] {
+            last = tracker.record_kill(t).0;
+        }
+        assert_eq!(last, KillContext::Penta);
+    }
+
+    #[test]
+    fn only_the_very_first_kill_is_flagged_as_first_kill() {
+        let mut tracker = KillTracker::new();
+        assert!(tracker.record_kill(0.0).1);
+        assert!(!tracker.record_kill(5.0).1);
+        assert!(!tracker.record_kill(100.0).1);
+    }
+
+    #[test]
+    fn meets_threshold_filters_out_kills_below_the_configured_minimum() {
+        assert!(!KillContext::Single.meets_threshold(KillContext::Double));
+        assert!(KillContext::Double.meets_threshold(KillContext::Double));
+        assert!(KillContext::Penta.meets_threshold(KillContext::Double));
+    }
+
+    #[test]
+    fn ally_and_enemy_multikills_are_tracked_independently() {
+        let mut tracker = TeamMultikillTracker::new();
+        let ally = tracker.record_kill("ally-1", Team::Ally, 0.0);
+        let enemy = tracker.record_kill("enemy-1", Team::Enemy, 1.0);
+        assert_eq!(ally, MultikillEvent { team: Team::Ally, context: KillContext::Single });
+        assert_eq!(enemy, MultikillEvent { team: Team::Enemy, context: KillContext::Single });
+
+        let ally_double = tracker.record_kill("ally-1", Team::Ally, 3.0);
+        assert_eq!(ally_double.context, KillContext::Double);
+        // Enemy's streak is unaffected by the ally's kills.
+        let enemy_double = tracker.record_kill("enemy-1", Team::Enemy, 4.0);
+        assert_eq!(enemy_double.context, KillContext::Double);
+    }
+
+    #[test]
+    fn plain_kill_is_suppressed_when_a_multikill_lands_within_the_window() {
+        let mut suppressor = DuplicateKillSuppressor::new();
+        suppressor.record_multikill("Faker", 100.0);
+        assert!(suppressor.should_suppress_plain_kill("Faker", 100.5));
+    }
+
+    #[test]
+    fn plain_kill_is_not_suppressed_outside_the_window() {
+        let mut suppressor = DuplicateKillSuppressor::new();
+        suppressor.record_multikill("Faker", 100.0);
+        assert!(!suppressor.should_suppress_plain_kill("Faker", 102.0));
+    }
+
+    #[test]
+    fn plain_kill_is_not_suppressed_for_a_different_killer() {
+        let mut suppressor = DuplicateKillSuppressor::new();
+        suppressor.record_multikill("Faker", 100.0);
+        assert!(!suppressor.should_suppress_plain_kill("Chovy", 100.1));
+    }
+
+    #[test]
+    fn no_suppression_when_no_multikill_has_been_recorded() {
+        let suppressor = DuplicateKillSuppressor::new();
+        assert!(!suppressor.should_suppress_plain_kill("Faker", 100.0));
+    }
+}
@@ -0,0 +1,59 @@
+/// Outcome of a previously completed match, most recent first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchOutcome {
+    Win,
+    Loss,
+}
+
+/// Context pulled from recent match history at game start, so rules can
+/// react to things like "on a losing streak" even though the live game
+/// itself has no way of knowing that.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchHistoryContext {
+    /// Length of the current win or loss streak, 0 if the last match is
+    /// unknown or there is no history yet.
+    pub current_streak: u32,
+    pub streak_outcome: Option<MatchOutcome>,
+}
+
+impl MatchHistoryContext {
+    /// Build context from recent match outcomes, most recent first.
+    pub fn from_recent_matches(recent: &[MatchOutcome]) -> Self {
+        let Some(&first) = recent.first() else {
+            return MatchHistoryContext::default();
+        };
+        let streak = recent.iter().take_while(|&&o| o == first).count() as u32;
+        MatchHistoryContext {
+            current_streak: streak,
+            streak_outcome: Some(first),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use MatchOutcome::*;
+
+    #[test]
+    fn no_history_yields_default_context() {
+        assert_eq!(
+            MatchHistoryContext::from_recent_matches(&[]),
+            MatchHistoryContext::default()
+        );
+    }
+
+    #[test]
+    fn counts_the_current_streak() {
+        let ctx = MatchHistoryContext::from_recent_matches(&[Loss, Loss, Loss, Win]);
+        assert_eq!(ctx.current_streak, 3);
+        assert_eq!(ctx.streak_outcome, Some(Loss));
+    }
+
+    #[test]
+    fn single_match_is_a_streak_of_one() {
+        let ctx = MatchHistoryContext::from_recent_matches(&[Win]);
+        assert_eq!(ctx.current_streak, 1);
+        assert_eq!(ctx.streak_outcome, Some(Win));
+    }
+}
@@ -0,0 +1,229 @@
+pub mod comeback;
+pub mod event_log;
+pub mod events;
+pub mod items;
+pub mod kills;
+pub mod match_history;
+pub mod objectives;
+pub mod post_caps;
+pub mod role;
+pub mod snapshot;
+pub mod spectator;
+pub mod summary;
+pub mod unhandled;
+
+pub use comeback::{ComebackThresholds, FightOutcome, TeamDiff};
+pub use event_log::EventLog;
+pub use events::{GameEvent, PingKind};
+pub use items::ItemTracker;
+pub use kills::{DuplicateKillSuppressor, KillContext, KillTracker, MultikillEvent, Team, TeamMultikillTracker};
+pub use match_history::MatchHistoryContext;
+pub use post_caps::DiscordPostCaps;
+pub use role::Role;
+pub use snapshot::{get_game_state_snapshot, GameStateSnapshot};
+pub use spectator::{FollowedTeam, LiveClientTeam};
+pub use summary::{GameSummary, LastGameSummary};
+pub use unhandled::UnhandledEvents;
+
+/// Tracks what we know about the live game, derived from Live Client API
+/// polls plus timers for things the API never tells us directly.
+#[derive(Debug)]
+pub struct GameState {
+    /// Seconds since the game started, as last reported by the Live Client API.
+    pub game_time: f64,
+    spawn_tracker: objectives::SpawnTracker,
+    pub local_player_kills: KillTracker,
+    pub match_history: MatchHistoryContext,
+    /// The local team's current standing versus the enemy, updated from
+    /// Live Client API reads as the game goes on.
+    pub team_diff: TeamDiff,
+    pub comeback_thresholds: ComebackThresholds,
+    item_tracker: ItemTracker,
+    /// Whether polling/announcements should still be running. Flipped to
+    /// `false` by [`GameState::handle_game_end`] when
+    /// `auto_stop_on_game_end` is set, so callers can check this after
+    /// every poll instead of tearing down state themselves.
+    pub is_monitoring: bool,
+    /// When set, [`GameState::handle_game_end`] stops monitoring as soon as
+    /// the game ends instead of leaving it running indefinitely.
+    pub auto_stop_on_game_end: bool,
+    /// Whether the local player is currently dead, as last reported by the
+    /// Live Client API. Used to quiet callouts via
+    /// [`crate::sound::DeadPlayerVolume`] instead of suppressing them
+    /// outright.
+    pub local_player_is_dead: bool,
+    duplicate_kill_suppressor: DuplicateKillSuppressor,
+    /// Which side to classify as ally when there's no local player to
+    /// derive it from, e.g. spectating.
+    pub followed_team: FollowedTeam,
+    pub discord_post_caps: DiscordPostCaps,
+    /// Transient "event-only" mode: when set, events still get recorded
+    /// and forwarded as normal, but no sound plays for them. Distinct from
+    /// any config-level mute since it's a session toggle that always
+    /// starts unpaused, not something saved to disk.
+    pub playback_paused: bool,
+    /// Raw Live Client event names seen this session that didn't match any
+    /// known [`GameEvent`] kind — see [`Self::record_unhandled_event`].
+    pub unhandled_events: UnhandledEvents,
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        GameState {
+            game_time: 0.0,
+            spawn_tracker: objectives::SpawnTracker::default(),
+            local_player_kills: KillTracker::default(),
+            match_history: MatchHistoryContext::default(),
+            team_diff: TeamDiff::default(),
+            comeback_thresholds: ComebackThresholds::default(),
+            item_tracker: ItemTracker::default(),
+            is_monitoring: true,
+            auto_stop_on_game_end: false,
+            local_player_is_dead: false,
+            duplicate_kill_suppressor: DuplicateKillSuppressor::default(),
+            followed_team: FollowedTeam::default(),
+            discord_post_caps: DiscordPostCaps::default(),
+            playback_paused: false,
+            unhandled_events: UnhandledEvents::default(),
+        }
+    }
+}
+
+impl GameState {
+    pub fn new() -> Self {
+        GameState::default()
+    }
+
+    /// Advance to a new game-time reading, returning any synthetic events
+    /// that fired as a result (e.g. objective spawns).
+    pub fn advance(&mut self, game_time: f64) -> Vec<GameEvent> {
+        let events = self.spawn_tracker.poll(self.game_time, game_time);
+        self.game_time = game_time;
+        events
+    }
+
+    /// Initialize state for a game that's already in progress (e.g. the app
+    /// restarted mid-game), silently catching timers up to `game_time`
+    /// instead of firing every spawn event that's already passed.
+    pub fn reconnect(game_time: f64) -> Self {
+        let mut state = GameState::new();
+        state.spawn_tracker.fast_forward(game_time);
+        state.game_time = game_time;
+        state
+    }
+
+    /// Record a kill by the local player, classifying it (single/double/...)
+    /// and flagging whether it's their first kill of the game.
+    pub fn record_local_player_kill(&mut self) -> (KillContext, bool) {
+        self.local_player_kills.record_kill(self.game_time)
+    }
+
+    /// Record the outcome of a teamfight against the currently-tracked
+    /// [`TeamDiff`], returning a [`GameEvent::Comeback`] if it qualifies.
+    pub fn record_team_fight(&mut self, outcome: FightOutcome) -> Option<GameEvent> {
+        comeback::detect_comeback(self.team_diff, outcome, self.comeback_thresholds)
+    }
+
+    /// Diff a freshly-polled completed-item list against the last poll,
+    /// returning a [`GameEvent::ItemComplete`] for each newly-completed item.
+    pub fn record_completed_items(&mut self, completed_items: &[String]) -> Vec<GameEvent> {
+        self.item_tracker.poll(completed_items)
+    }
+
+    /// Record that `killer` landed a multikill at the current game time, so
+    /// a plain kill event for them arriving around the same moment can be
+    /// recognized as a duplicate signal rather than a separate kill.
+    pub fn record_multikill_for_duplicate_suppression(&mut self, killer: &str) {
+        self.duplicate_kill_suppressor.record_multikill(killer, self.game_time);
+    }
+
+    /// Whether a plain kill sound for `killer` should be suppressed because
+    /// a multikill event for them landed within the duplicate-kill window.
+    pub fn should_suppress_plain_kill(&self, killer: &str) -> bool {
+        self.duplicate_kill_suppressor.should_suppress_plain_kill(killer, self.game_time)
+    }
+
+    /// Called once the game has been detected as over. Stops monitoring if
+    /// `auto_stop_on_game_end` is set; otherwise leaves monitoring running
+    /// until the caller stops it explicitly. Either way, resets the Discord
+    /// post counts for the next game.
+    pub fn handle_game_end(&mut self) {
+        if self.auto_stop_on_game_end {
+            self.is_monitoring = false;
+        }
+        self.discord_post_caps.reset();
+    }
+
+    /// Stop playing sounds for events without stopping monitoring.
+    pub fn pause_playback(&mut self) {
+        self.playback_paused = true;
+    }
+
+    /// Resume playing sounds for events after [`Self::pause_playback`].
+    pub fn resume_playback(&mut self) {
+        self.playback_paused = false;
+    }
+
+    /// Record that a raw Live Client event named `name` didn't match any
+    /// known [`GameEvent`] kind, so it shows up in
+    /// [`UnhandledEvents::get_unhandled_events`] for later prioritization.
+    pub fn record_unhandled_event(&mut self, name: impl Into<String>) {
+        self.unhandled_events.record_unhandled(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_end_stops_monitoring_when_auto_stop_is_enabled() {
+        let mut state = GameState::new();
+        state.auto_stop_on_game_end = true;
+        state.handle_game_end();
+        assert!(!state.is_monitoring);
+    }
+
+    #[test]
+    fn game_end_leaves_monitoring_running_by_default() {
+        let mut state = GameState::new();
+        state.handle_game_end();
+        assert!(state.is_monitoring);
+    }
+
+    #[test]
+    fn game_end_resets_discord_post_caps_for_the_next_game() {
+        let mut state = GameState::new();
+        state.discord_post_caps.set_cap("objective_taken_ally", 1);
+        assert!(state.discord_post_caps.try_record_post("objective_taken_ally"));
+        assert!(!state.discord_post_caps.try_record_post("objective_taken_ally"));
+
+        state.handle_game_end();
+
+        assert!(state.discord_post_caps.try_record_post("objective_taken_ally"));
+    }
+
+    #[test]
+    fn pausing_playback_does_not_stop_monitoring() {
+        let mut state = GameState::new();
+        state.pause_playback();
+        assert!(state.playback_paused);
+        assert!(state.is_monitoring);
+    }
+
+    #[test]
+    fn resume_playback_clears_the_pause() {
+        let mut state = GameState::new();
+        state.pause_playback();
+        state.resume_playback();
+        assert!(!state.playback_paused);
+    }
+
+    #[test]
+    fn an_unhandled_event_is_recorded_on_the_game_state() {
+        let mut state = GameState::new();
+        state.record_unhandled_event("ChampionKillSpree");
+
+        assert_eq!(state.unhandled_events.get_unhandled_events(), vec!["ChampionKillSpree".to_string()]);
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+/// Caps how many times each event kind (see [`super::GameEvent::kind_name`])
+/// can trigger a Discord text post in a single game — e.g. only the first
+/// 3 dragon secures — so long games don't spam the channel. Voice playback
+/// is unaffected by this; it only gates text posts.
+#[derive(Debug, Default)]
+pub struct DiscordPostCaps {
+    max_posts_per_kind: HashMap<String, u32>,
+    posts_so_far: HashMap<String, u32>,
+}
+
+impl DiscordPostCaps {
+    pub fn new() -> Self {
+        DiscordPostCaps::default()
+    }
+
+    /// Configure a cap of `max_posts` text posts per game for `event_kind`.
+    /// Event kinds with no configured cap are never gated.
+    pub fn set_cap(&mut self, event_kind: &str, max_posts: u32) {
+        self.max_posts_per_kind.insert(event_kind.to_string(), max_posts);
+    }
+
+    /// Whether a text post for `event_kind` should go out right now. Each
+    /// call that returns `true` counts against the cap; kinds with no
+    /// configured cap always return `true`.
+    pub fn try_record_post(&mut self, event_kind: &str) -> bool {
+        let Some(&max) = self.max_posts_per_kind.get(event_kind) else {
+            return true;
+        };
+        let count = self.posts_so_far.entry(event_kind.to_string()).or_insert(0);
+        if *count >= max {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
+    /// Reset post counts (but not configured caps) for a new game.
+    pub fn reset(&mut self) {
+        self.posts_so_far.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uncapped_kinds_always_post() {
+        let mut caps = DiscordPostCaps::new();
+        for _ in 0..10 {
+            assert!(caps.try_record_post("objective_taken_ally"));
+        }
+    }
+
+    #[test]
+    fn the_fourth_post_is_gated_by_a_cap_of_three() {
+        let mut caps = DiscordPostCaps::new();
+        caps.set_cap("objective_taken_ally", 3);
+
+        assert!(caps.try_record_post("objective_taken_ally"));
+        assert!(caps.try_record_post("objective_taken_ally"));
+        assert!(caps.try_record_post("objective_taken_ally"));
+        assert!(!caps.try_record_post("objective_taken_ally"));
+    }
+
+    #[test]
+    fn caps_are_tracked_independently_per_event_kind() {
+        let mut caps = DiscordPostCaps::new();
+        caps.set_cap("objective_taken_ally", 1);
+
+        assert!(caps.try_record_post("objective_taken_ally"));
+        assert!(!caps.try_record_post("objective_taken_ally"));
+        assert!(caps.try_record_post("comeback"));
+    }
+
+    #[test]
+    fn reset_clears_counts_but_keeps_the_configured_cap() {
+        let mut caps = DiscordPostCaps::new();
+        caps.set_cap("objective_taken_ally", 1);
+        assert!(caps.try_record_post("objective_taken_ally"));
+        assert!(!caps.try_record_post("objective_taken_ally"));
+
+        caps.reset();
+
+        assert!(caps.try_record_post("objective_taken_ally"));
+        assert!(!caps.try_record_post("objective_taken_ally"));
+    }
+}
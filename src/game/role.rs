@@ -0,0 +1,42 @@
+/// Player role/lane, as reported by the Live Client API's `position` field
+/// (itself derived from the pre-game role select, not live positioning).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Top,
+    Jungle,
+    Mid,
+    Bottom,
+    Support,
+}
+
+impl Role {
+    /// Parse the Live Client API's `position` string (e.g. `"TOP"`,
+    /// `"JUNGLE"`, `"MIDDLE"`, `"BOTTOM"`, `"UTILITY"`).
+    pub fn from_live_client_position(position: &str) -> Option<Role> {
+        match position.to_ascii_uppercase().as_str() {
+            "TOP" => Some(Role::Top),
+            "JUNGLE" => Some(Role::Jungle),
+            "MIDDLE" | "MID" => Some(Role::Mid),
+            "BOTTOM" | "BOT" | "ADC" => Some(Role::Bottom),
+            "UTILITY" | "SUPPORT" => Some(Role::Support),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_positions() {
+        assert_eq!(Role::from_live_client_position("JUNGLE"), Some(Role::Jungle));
+        assert_eq!(Role::from_live_client_position("utility"), Some(Role::Support));
+        assert_eq!(Role::from_live_client_position("MIDDLE"), Some(Role::Mid));
+    }
+
+    #[test]
+    fn unknown_position_is_none() {
+        assert_eq!(Role::from_live_client_position("NONE"), None);
+    }
+}
@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use super::spectator::LiveClientTeam;
+use super::{GameState, TeamDiff};
+
+/// A serializable view of [`GameState`] for debugging, e.g. a support
+/// command that dumps what the poller currently thinks is happening in the
+/// game without exposing the mutable state itself.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameStateSnapshot {
+    pub game_time: f64,
+    pub in_game: bool,
+    pub local_player_is_dead: bool,
+    pub local_player_kills: u32,
+    pub followed_team: LiveClientTeam,
+    pub team_diff: TeamDiff,
+}
+
+/// Build a point-in-time snapshot of `state` for debugging/support use.
+pub fn get_game_state_snapshot(state: &GameState) -> GameStateSnapshot {
+    GameStateSnapshot {
+        game_time: state.game_time,
+        in_game: state.is_monitoring,
+        local_player_is_dead: state.local_player_is_dead,
+        local_player_kills: state.local_player_kills.total_kills(),
+        followed_team: state.followed_team.followed(),
+        team_diff: state.team_diff,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_populated_snapshot_serializes_with_the_expected_fields() {
+        let mut state = GameState::new();
+        state.game_time = 754.0;
+        state.local_player_is_dead = true;
+        state.team_diff = TeamDiff { gold_diff: -2500, kill_diff: -2 };
+        state.record_local_player_kill();
+        state.record_local_player_kill();
+
+        let snapshot = get_game_state_snapshot(&state);
+        let json = serde_json::to_value(&snapshot).unwrap();
+
+        assert_eq!(json["game_time"], 754.0);
+        assert_eq!(json["in_game"], true);
+        assert_eq!(json["local_player_is_dead"], true);
+        assert_eq!(json["local_player_kills"], 2);
+        assert_eq!(json["followed_team"], "Order");
+        assert_eq!(json["team_diff"]["gold_diff"], -2500);
+        assert_eq!(json["team_diff"]["kill_diff"], -2);
+    }
+}
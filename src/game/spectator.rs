@@ -0,0 +1,76 @@
+/// Riot's raw team identifiers from the Live Client API, before mapping to
+/// the local perspective's [`super::Team::Ally`]/[`super::Team::Enemy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum LiveClientTeam {
+    Order,
+    Chaos,
+}
+
+/// Which team's perspective to classify Ally/Enemy from when there's no
+/// local player to derive it from (spectating, or a player who wants
+/// enemy-perspective sounds). Defaults to [`LiveClientTeam::Order`], Riot's
+/// usual "blue side".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FollowedTeam {
+    followed: LiveClientTeam,
+}
+
+impl Default for FollowedTeam {
+    fn default() -> Self {
+        FollowedTeam { followed: LiveClientTeam::Order }
+    }
+}
+
+impl LiveClientTeam {
+    /// The other side of the map from this one.
+    pub fn opposite(self) -> LiveClientTeam {
+        match self {
+            LiveClientTeam::Order => LiveClientTeam::Chaos,
+            LiveClientTeam::Chaos => LiveClientTeam::Order,
+        }
+    }
+}
+
+impl FollowedTeam {
+    /// Set which team's perspective ally/enemy classification should use.
+    pub fn set_followed_team(&mut self, team: LiveClientTeam) {
+        self.followed = team;
+    }
+
+    /// The team currently being followed.
+    pub fn followed(&self) -> LiveClientTeam {
+        self.followed
+    }
+
+    /// Whether `team` is the ally side from the currently followed
+    /// perspective.
+    pub fn is_ally_team(&self, team: LiveClientTeam) -> bool {
+        team == self.followed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_following_order_as_the_ally_side() {
+        let followed = FollowedTeam::default();
+        assert!(followed.is_ally_team(LiveClientTeam::Order));
+        assert!(!followed.is_ally_team(LiveClientTeam::Chaos));
+    }
+
+    #[test]
+    fn following_chaos_flips_which_side_counts_as_ally() {
+        let mut followed = FollowedTeam::default();
+        followed.set_followed_team(LiveClientTeam::Chaos);
+        assert!(followed.is_ally_team(LiveClientTeam::Chaos));
+        assert!(!followed.is_ally_team(LiveClientTeam::Order));
+    }
+
+    #[test]
+    fn opposite_flips_order_and_chaos() {
+        assert_eq!(LiveClientTeam::Order.opposite(), LiveClientTeam::Chaos);
+        assert_eq!(LiveClientTeam::Chaos.opposite(), LiveClientTeam::Order);
+    }
+}
@@ -0,0 +1,49 @@
+/// A condensed record of a finished game, kept around so its summary can
+/// be re-announced on request (e.g. a teammate joins Discord late).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameSummary {
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub won: Option<bool>,
+}
+
+/// Holds the most recently completed game's summary, if any.
+#[derive(Debug, Default)]
+pub struct LastGameSummary {
+    summary: Option<GameSummary>,
+}
+
+impl LastGameSummary {
+    pub fn new() -> Self {
+        LastGameSummary::default()
+    }
+
+    pub fn set(&mut self, summary: GameSummary) {
+        self.summary = Some(summary);
+    }
+
+    /// Re-emit the last game's summary, or `None` if no game has finished
+    /// yet this session.
+    pub fn re_emit(&self) -> Option<&GameSummary> {
+        self.summary.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn re_emit_returns_none_before_any_game_finishes() {
+        assert_eq!(LastGameSummary::new().re_emit(), None);
+    }
+
+    #[test]
+    fn re_emit_returns_the_last_recorded_summary() {
+        let mut last = LastGameSummary::new();
+        let summary = GameSummary { kills: 5, deaths: 2, assists: 10, won: Some(true) };
+        last.set(summary.clone());
+        assert_eq!(last.re_emit(), Some(&summary));
+    }
+}
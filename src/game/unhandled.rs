@@ -0,0 +1,63 @@
+/// Tracks raw Live Client event names that didn't map to any known
+/// [`super::GameEvent`] variant, so unsupported-but-common events can be
+/// prioritized for future support based on real session data instead of
+/// silently dropped wherever raw Live Client events are parsed.
+#[derive(Debug, Default)]
+pub struct UnhandledEvents {
+    seen: std::collections::BTreeSet<String>,
+}
+
+impl UnhandledEvents {
+    pub fn new() -> Self {
+        UnhandledEvents::default()
+    }
+
+    /// Record that a raw event named `name` didn't match any known kind.
+    pub fn record_unhandled(&mut self, name: impl Into<String>) {
+        self.seen.insert(name.into());
+    }
+
+    /// Every distinct unhandled event name recorded so far this session, sorted.
+    pub fn get_unhandled_events(&self) -> Vec<String> {
+        self.seen.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unhandled_event_name_is_recorded() {
+        let mut unhandled = UnhandledEvents::new();
+        unhandled.record_unhandled("ChampionKillSpree");
+
+        assert_eq!(unhandled.get_unhandled_events(), vec!["ChampionKillSpree".to_string()]);
+    }
+
+    #[test]
+    fn recording_the_same_name_twice_does_not_duplicate_it() {
+        let mut unhandled = UnhandledEvents::new();
+        unhandled.record_unhandled("DragonKill");
+        unhandled.record_unhandled("DragonKill");
+
+        assert_eq!(unhandled.get_unhandled_events(), vec!["DragonKill".to_string()]);
+    }
+
+    #[test]
+    fn distinct_names_are_reported_sorted() {
+        let mut unhandled = UnhandledEvents::new();
+        unhandled.record_unhandled("TurretKilled");
+        unhandled.record_unhandled("BaronKill");
+
+        assert_eq!(
+            unhandled.get_unhandled_events(),
+            vec!["BaronKill".to_string(), "TurretKilled".to_string()]
+        );
+    }
+
+    #[test]
+    fn a_fresh_tracker_reports_nothing() {
+        assert!(UnhandledEvents::new().get_unhandled_events().is_empty());
+    }
+}
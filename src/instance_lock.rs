@@ -0,0 +1,99 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// The single-instance lock, held for as long as this value lives. Backed
+/// by a lockfile created exclusively under app data, so a second process
+/// trying to start monitoring at the same time is refused up front instead
+/// of causing double sounds.
+pub struct InstanceLock {
+    path: PathBuf,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LockError {
+    #[error("another instance is already running (lockfile at {0:?})")]
+    AlreadyLocked(PathBuf),
+    #[error("failed to create lockfile at {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl InstanceLock {
+    /// Try to acquire the lock at `path`. Fails with
+    /// [`LockError::AlreadyLocked`] if another process already holds it.
+    pub fn acquire(path: impl AsRef<Path>) -> Result<Self, LockError> {
+        let path = path.as_ref().to_path_buf();
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                let _ = write!(file, "{}", std::process::id());
+                Ok(InstanceLock { path })
+            }
+            Err(source) if source.kind() == std::io::ErrorKind::AlreadyExists => {
+                Err(LockError::AlreadyLocked(path))
+            }
+            Err(source) => Err(LockError::Io { path, source }),
+        }
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Whether another instance currently holds the lock at `path`, without
+/// trying to acquire it — lets startup show a clear message before even
+/// attempting [`InstanceLock::acquire`].
+pub fn is_another_instance_running(path: impl AsRef<Path>) -> bool {
+    path.as_ref().exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("scout-for-lol-test-{name}.lock"))
+    }
+
+    #[test]
+    fn acquiring_a_free_lock_succeeds_and_is_detected() {
+        let path = lock_path("acquire-succeeds");
+        let _ = std::fs::remove_file(&path);
+
+        assert!(!is_another_instance_running(&path));
+        let lock = InstanceLock::acquire(&path).unwrap();
+        assert!(is_another_instance_running(&path));
+
+        drop(lock);
+        assert!(!is_another_instance_running(&path));
+    }
+
+    #[test]
+    fn acquiring_an_already_held_lock_fails() {
+        let path = lock_path("acquire-fails");
+        let _ = std::fs::remove_file(&path);
+
+        let lock = InstanceLock::acquire(&path).unwrap();
+        assert!(matches!(InstanceLock::acquire(&path), Err(LockError::AlreadyLocked(_))));
+
+        drop(lock);
+    }
+
+    #[test]
+    fn releasing_the_lock_allows_reacquiring_it() {
+        let path = lock_path("reacquire");
+        let _ = std::fs::remove_file(&path);
+
+        let lock = InstanceLock::acquire(&path).unwrap();
+        drop(lock);
+
+        assert!(InstanceLock::acquire(&path).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+}
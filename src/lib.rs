@@ -0,0 +1,9 @@
+pub mod config;
+pub mod discord;
+pub mod game;
+pub mod instance_lock;
+pub mod logs;
+pub mod lol_client;
+pub mod rules;
+pub mod sound;
+pub mod youtube;
@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Logging verbosity, ordered least to most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => LogLevel::Error,
+            1 => LogLevel::Warn,
+            2 => LogLevel::Info,
+            3 => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        }
+    }
+}
+
+/// Global, runtime-adjustable log level so users can turn on verbose
+/// logging to diagnose an issue without restarting the app.
+static CURRENT_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Info as u8);
+
+pub fn set_log_level(level: LogLevel) {
+    CURRENT_LEVEL.store(level.as_u8(), Ordering::Relaxed);
+}
+
+pub fn current_log_level() -> LogLevel {
+    LogLevel::from_u8(CURRENT_LEVEL.load(Ordering::Relaxed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_log_level_updates_the_global_level() {
+        set_log_level(LogLevel::Debug);
+        assert_eq!(current_log_level(), LogLevel::Debug);
+        set_log_level(LogLevel::Info);
+    }
+}
@@ -0,0 +1,35 @@
+pub mod level;
+
+use std::path::{Path, PathBuf};
+
+pub use level::{current_log_level, set_log_level, LogLevel};
+
+/// Copy the current log file to `dest`, then truncate the original so the
+/// next session starts from a clean slate. Doing both in one call avoids
+/// the window where a crash between "export" and "reset" loses logs.
+pub fn export_and_reset(log_path: &Path, dest: &Path) -> std::io::Result<PathBuf> {
+    std::fs::copy(log_path, dest)?;
+    std::fs::write(log_path, b"")?;
+    Ok(dest.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_copies_then_truncates_the_log() {
+        let dir = std::env::temp_dir().join(format!("scout-log-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log_path = dir.join("scout.log");
+        let dest = dir.join("scout.log.bak");
+        std::fs::write(&log_path, b"line one\nline two\n").unwrap();
+
+        export_and_reset(&log_path, &dest).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "line one\nline two\n");
+        assert_eq!(std::fs::read_to_string(&log_path).unwrap(), "");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
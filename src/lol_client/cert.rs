@@ -0,0 +1,38 @@
+/// How the Live Client poller should handle the self-signed certificate
+/// Riot's local client binds to (and occasionally rotates).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertTrustMode {
+    /// Trust Riot's known Live Client certificate specifically, rejecting
+    /// anything else — the default, since it's the mode hardened
+    /// environments that block blanket invalid-cert acceptance allow.
+    PinnedCert,
+    /// Accept any invalid cert outright (`danger_accept_invalid_certs`).
+    /// Less safe, but the only option that keeps polling working across a
+    /// cert rotation Riot hasn't shipped a pin update for yet.
+    AcceptInvalidCerts,
+}
+
+/// Pick a trust mode for the Live Client connection: pin to `known_cert`
+/// when one is available, otherwise fall back to accepting invalid certs
+/// outright so a cert rotation doesn't break polling entirely.
+pub fn select_cert_trust_mode(known_cert: Option<&[u8]>) -> CertTrustMode {
+    match known_cert {
+        Some(_) => CertTrustMode::PinnedCert,
+        None => CertTrustMode::AcceptInvalidCerts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pins_the_cert_when_one_is_known() {
+        assert_eq!(select_cert_trust_mode(Some(b"riot-live-client-cert")), CertTrustMode::PinnedCert);
+    }
+
+    #[test]
+    fn falls_back_to_accepting_invalid_certs_when_no_pin_is_available() {
+        assert_eq!(select_cert_trust_mode(None), CertTrustMode::AcceptInvalidCerts);
+    }
+}
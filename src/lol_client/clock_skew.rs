@@ -0,0 +1,80 @@
+/// A single (wall-clock, game-time) sample from a Live Client poll, used to
+/// detect clock skew between the two.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSample {
+    pub wall_clock_seconds: f64,
+    pub game_time_seconds: f64,
+}
+
+/// How far game time has drifted from wall-clock time across a series of
+/// samples — time-window and cooldown features mix the two, so a large
+/// `drift_seconds` means those features may fire early or late.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSkewReport {
+    pub wall_clock_elapsed: f64,
+    pub game_time_elapsed: f64,
+    pub drift_seconds: f64,
+}
+
+/// Compare game-time progression against wall-clock elapsed across
+/// `samples` (oldest to newest) and report the drift between them. `None`
+/// if there are fewer than two samples to compare.
+pub fn detect_clock_skew(samples: &[ClockSample]) -> Option<ClockSkewReport> {
+    let first = samples.first()?;
+    let last = samples.last()?;
+    if samples.len() < 2 {
+        return None;
+    }
+
+    let wall_clock_elapsed = last.wall_clock_seconds - first.wall_clock_seconds;
+    let game_time_elapsed = last.game_time_seconds - first.game_time_seconds;
+    Some(ClockSkewReport {
+        wall_clock_elapsed,
+        game_time_elapsed,
+        drift_seconds: game_time_elapsed - wall_clock_elapsed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_sample_is_not_enough_to_detect_drift() {
+        let samples = [ClockSample { wall_clock_seconds: 0.0, game_time_seconds: 0.0 }];
+        assert_eq!(detect_clock_skew(&samples), None);
+    }
+
+    #[test]
+    fn no_drift_when_game_time_tracks_wall_clock_exactly() {
+        let samples = [
+            ClockSample { wall_clock_seconds: 0.0, game_time_seconds: 100.0 },
+            ClockSample { wall_clock_seconds: 10.0, game_time_seconds: 110.0 },
+        ];
+        let report = detect_clock_skew(&samples).unwrap();
+        assert_eq!(report.drift_seconds, 0.0);
+    }
+
+    #[test]
+    fn reports_positive_drift_when_game_time_outruns_wall_clock() {
+        let samples = [
+            ClockSample { wall_clock_seconds: 0.0, game_time_seconds: 0.0 },
+            ClockSample { wall_clock_seconds: 10.0, game_time_seconds: 25.0 },
+        ];
+        let report = detect_clock_skew(&samples).unwrap();
+        assert_eq!(report.wall_clock_elapsed, 10.0);
+        assert_eq!(report.game_time_elapsed, 25.0);
+        assert_eq!(report.drift_seconds, 15.0);
+    }
+
+    #[test]
+    fn only_the_first_and_last_samples_matter() {
+        let samples = [
+            ClockSample { wall_clock_seconds: 0.0, game_time_seconds: 0.0 },
+            ClockSample { wall_clock_seconds: 500.0, game_time_seconds: 999.0 },
+            ClockSample { wall_clock_seconds: 10.0, game_time_seconds: 10.0 },
+        ];
+        let report = detect_clock_skew(&samples).unwrap();
+        assert_eq!(report.drift_seconds, 0.0);
+    }
+}
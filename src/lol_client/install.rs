@@ -0,0 +1,56 @@
+use std::path::{Path, PathBuf};
+
+/// Standard per-platform install locations to probe, in the order a fresh
+/// install is most likely to land in.
+fn candidate_paths() -> Vec<PathBuf> {
+    if cfg!(target_os = "windows") {
+        vec![
+            PathBuf::from(r"C:\Riot Games\League of Legends"),
+            PathBuf::from(r"C:\Program Files\Riot Games\League of Legends"),
+            PathBuf::from(r"C:\Program Files (x86)\Riot Games\League of Legends"),
+        ]
+    } else if cfg!(target_os = "macos") {
+        vec![PathBuf::from("/Applications/League of Legends.app")]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Core of [`is_league_installed`], taking the candidate list and an
+/// existence check as parameters so it can be exercised against a fake
+/// filesystem layout in tests.
+pub fn find_installed(candidates: &[PathBuf], exists: impl Fn(&Path) -> bool) -> Option<PathBuf> {
+    candidates.iter().find(|p| exists(p)).cloned()
+}
+
+/// Check whether League of Legends appears to be installed in one of the
+/// standard per-platform locations, returning the first match.
+pub fn is_league_installed() -> Option<PathBuf> {
+    find_installed(&candidate_paths(), |p| p.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_first_existing_candidate() {
+        let candidates = vec![PathBuf::from("/does/not/exist"), PathBuf::from("/riot/league")];
+        let found = find_installed(&candidates, |p| p == Path::new("/riot/league"));
+        assert_eq!(found, Some(PathBuf::from("/riot/league")));
+    }
+
+    #[test]
+    fn returns_none_when_no_candidate_exists() {
+        let candidates = vec![PathBuf::from("/does/not/exist")];
+        let found = find_installed(&candidates, |_| false);
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn prefers_earlier_candidates_over_later_ones() {
+        let candidates = vec![PathBuf::from("/first"), PathBuf::from("/second")];
+        let found = find_installed(&candidates, |_| true);
+        assert_eq!(found, Some(PathBuf::from("/first")));
+    }
+}
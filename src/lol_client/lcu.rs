@@ -0,0 +1,51 @@
+/// Connection info for the League Client Update API, read from the
+/// `lockfile` League writes next to its install.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LcuConnection {
+    pub port: u16,
+    pub auth_token: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LcuTestError {
+    #[error("no lockfile found; is the League client running?")]
+    NoLockfile,
+    #[error("lockfile at {0} is malformed")]
+    MalformedLockfile(std::path::PathBuf),
+}
+
+/// Parse `League of Legends/lockfile`'s `name:pid:port:password:protocol`
+/// format into an [`LcuConnection`], without persisting anything — used to
+/// let users verify the LCU is reachable before wiring it into the app.
+pub fn parse_lockfile(contents: &str, path: &std::path::Path) -> Result<LcuConnection, LcuTestError> {
+    let fields: Vec<&str> = contents.trim().split(':').collect();
+    let [_name, _pid, port, password, _protocol] = fields[..] else {
+        return Err(LcuTestError::MalformedLockfile(path.to_path_buf()));
+    };
+    let port: u16 = port
+        .parse()
+        .map_err(|_| LcuTestError::MalformedLockfile(path.to_path_buf()))?;
+    Ok(LcuConnection { port, auth_token: password.to_string() })
+}
+
+pub fn test_connect(lockfile_path: &std::path::Path) -> Result<LcuConnection, LcuTestError> {
+    let contents = std::fs::read_to_string(lockfile_path).map_err(|_| LcuTestError::NoLockfile)?;
+    parse_lockfile(&contents, lockfile_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn parses_a_well_formed_lockfile() {
+        let conn = parse_lockfile("LeagueClient:1234:56789:abc123:https", Path::new("lockfile")).unwrap();
+        assert_eq!(conn, LcuConnection { port: 56789, auth_token: "abc123".into() });
+    }
+
+    #[test]
+    fn rejects_malformed_lockfiles() {
+        assert!(parse_lockfile("garbage", Path::new("lockfile")).is_err());
+    }
+}
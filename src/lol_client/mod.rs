@@ -0,0 +1,90 @@
+pub mod cert;
+pub mod clock_skew;
+pub mod install;
+pub mod lcu;
+pub mod retry;
+pub mod safe_poll;
+pub mod test_mode;
+
+use serde::Deserialize;
+
+pub use cert::{select_cert_trust_mode, CertTrustMode};
+pub use clock_skew::{detect_clock_skew, ClockSample, ClockSkewReport};
+pub use install::is_league_installed;
+pub use retry::{poll_with_retry, RetryConfig};
+pub use safe_poll::parse_snapshot_safely;
+pub use test_mode::TestModeSource;
+
+/// A raw poll of the Live Client API's `/liveclientdata/allgamedata`
+/// endpoint. Every field is optional because Riot ships partial payloads
+/// mid-load (e.g. `activePlayer` missing for the first second or two of
+/// champ select -> loading screen transition).
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LiveClientSnapshot {
+    #[serde(rename = "gameData")]
+    pub game_data: Option<GameData>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GameData {
+    #[serde(rename = "gameTime")]
+    pub game_time: Option<f64>,
+}
+
+impl LiveClientSnapshot {
+    /// Best-effort game time, defaulting to 0.0 rather than failing the
+    /// whole poll when the payload is partial.
+    pub fn game_time_or_default(&self) -> f64 {
+        self.game_data
+            .as_ref()
+            .and_then(|d| d.game_time)
+            .unwrap_or(0.0)
+    }
+}
+
+/// The UI-facing "what time is it in the game" query: `None` when `raw`
+/// isn't a live game poll at all (no `gameData`), as opposed to
+/// [`LiveClientSnapshot::game_time_or_default`] which is for callers (like
+/// time-window rule evaluation) that need a number regardless. `raw` is
+/// whatever [`parse_snapshot_safely`] already recovered from the poll.
+pub fn get_game_time(raw: &LiveClientSnapshot) -> Option<f64> {
+    raw.game_data.as_ref().and_then(|d| d.game_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_game_data_defaults_game_time_to_zero() {
+        let snapshot: LiveClientSnapshot = serde_json::from_str("{}").unwrap();
+        assert_eq!(snapshot.game_time_or_default(), 0.0);
+    }
+
+    #[test]
+    fn partial_game_data_still_parses() {
+        let snapshot: LiveClientSnapshot =
+            serde_json::from_str(r#"{"gameData": {}}"#).unwrap();
+        assert_eq!(snapshot.game_time_or_default(), 0.0);
+    }
+
+    #[test]
+    fn full_game_data_reports_the_real_time() {
+        let snapshot: LiveClientSnapshot =
+            serde_json::from_str(r#"{"gameData": {"gameTime": 123.5}}"#).unwrap();
+        assert_eq!(snapshot.game_time_or_default(), 123.5);
+    }
+
+    #[test]
+    fn get_game_time_is_none_when_not_in_game() {
+        let snapshot: LiveClientSnapshot = serde_json::from_str("{}").unwrap();
+        assert_eq!(get_game_time(&snapshot), None);
+    }
+
+    #[test]
+    fn get_game_time_maps_gamestats_to_the_time_value() {
+        let snapshot: LiveClientSnapshot =
+            serde_json::from_str(r#"{"gameData": {"gameTime": 42.0}}"#).unwrap();
+        assert_eq!(get_game_time(&snapshot), Some(42.0));
+    }
+}
@@ -0,0 +1,63 @@
+/// How to retry a transient polling failure (connection refused while the
+/// client is loading, a dropped TLS handshake, etc.) before giving up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub backoff: std::time::Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig { max_attempts: 3, backoff: std::time::Duration::from_millis(500) }
+    }
+}
+
+/// Run `poll` up to `config.max_attempts` times, sleeping `config.backoff`
+/// between attempts, returning the first success or the last error.
+pub fn poll_with_retry<T, E>(
+    config: RetryConfig,
+    mut poll: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match poll() {
+            Ok(value) => return Ok(value),
+            Err(_) if attempt < config.max_attempts => {
+                std::thread::sleep(config.backoff);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    #[test]
+    fn succeeds_on_a_later_attempt_within_the_limit() {
+        let attempts = Cell::new(0);
+        let config = RetryConfig { max_attempts: 3, backoff: Duration::ZERO };
+        let result: Result<&str, &str> = poll_with_retry(config, || {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() < 2 { Err("transient") } else { Ok("ok") }
+        });
+        assert_eq!(result, Ok("ok"));
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let attempts = Cell::new(0);
+        let config = RetryConfig { max_attempts: 2, backoff: Duration::ZERO };
+        let result: Result<&str, &str> = poll_with_retry(config, || {
+            attempts.set(attempts.get() + 1);
+            Err("still failing")
+        });
+        assert_eq!(result, Err("still failing"));
+        assert_eq!(attempts.get(), 2);
+    }
+}
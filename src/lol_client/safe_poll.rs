@@ -0,0 +1,29 @@
+use super::LiveClientSnapshot;
+
+/// Parse one poll response, logging and skipping it on failure instead of
+/// propagating the error and killing the poll loop over a single bad
+/// payload (Riot's endpoint occasionally returns truncated JSON mid-write).
+pub fn parse_snapshot_safely(raw: &str) -> Option<LiveClientSnapshot> {
+    match serde_json::from_str(raw) {
+        Ok(snapshot) => Some(snapshot),
+        Err(err) => {
+            eprintln!("scout-for-lol: skipping malformed live client payload: {err}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_json_is_skipped_not_propagated() {
+        assert!(parse_snapshot_safely("{not json").is_none());
+    }
+
+    #[test]
+    fn valid_json_still_parses() {
+        assert!(parse_snapshot_safely("{}").is_some());
+    }
+}
@@ -0,0 +1,55 @@
+use crate::game::GameEvent;
+
+/// Feeds a scripted sequence of events instead of polling the real Live
+/// Client API, so the rest of the pipeline (rules -> sound playback) can
+/// be exercised without a live game running.
+#[derive(Debug, Default)]
+pub struct TestModeSource {
+    scripted: Vec<GameEvent>,
+    next: usize,
+}
+
+impl TestModeSource {
+    pub fn new(scripted: Vec<GameEvent>) -> Self {
+        TestModeSource { scripted, next: 0 }
+    }
+
+    /// Return the next scripted event, if any are left.
+    pub fn poll(&mut self) -> Option<GameEvent> {
+        let event = self.scripted.get(self.next).cloned();
+        if event.is_some() {
+            self.next += 1;
+        }
+        event
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.next >= self.scripted.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events::PingKind;
+
+    #[test]
+    fn polls_scripted_events_in_order_then_exhausts() {
+        let mut source = TestModeSource::new(vec![
+            GameEvent::Ping { kind: PingKind::Danger, from_local_player: true },
+            GameEvent::Ping { kind: PingKind::Retreat, from_local_player: false },
+        ]);
+
+        assert!(!source.is_exhausted());
+        assert_eq!(
+            source.poll(),
+            Some(GameEvent::Ping { kind: PingKind::Danger, from_local_player: true })
+        );
+        assert_eq!(
+            source.poll(),
+            Some(GameEvent::Ping { kind: PingKind::Retreat, from_local_player: false })
+        );
+        assert_eq!(source.poll(), None);
+        assert!(source.is_exhausted());
+    }
+}
@@ -0,0 +1,65 @@
+/// What to do when an event has no rule bound to it at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BaseBeepBehavior {
+    /// Play a generic beep so nothing passes silently.
+    #[default]
+    Beep,
+    /// Stay silent.
+    Silent,
+}
+
+use super::RulesEngine;
+use crate::game::GameEvent;
+
+impl RulesEngine {
+    /// Resolve what to play for `event`: its bound pools if any rule
+    /// matches, otherwise the base-beep behavior's fallback.
+    pub fn resolve_with_base_beep<'a>(
+        &'a self,
+        event: &GameEvent,
+        behavior: BaseBeepBehavior,
+    ) -> Vec<&'a str> {
+        let pools = self.matching_pools(event);
+        if !pools.is_empty() {
+            return pools;
+        }
+        match behavior {
+            BaseBeepBehavior::Beep => vec!["base_beep"],
+            BaseBeepBehavior::Silent => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events::PingKind;
+    use crate::rules::{Rule, RulesPack};
+
+    fn event() -> GameEvent {
+        GameEvent::Ping { kind: PingKind::Danger, from_local_player: true }
+    }
+
+    #[test]
+    fn unmapped_event_beeps_by_default() {
+        let engine = RulesEngine::new();
+        assert_eq!(engine.resolve_with_base_beep(&event(), BaseBeepBehavior::Beep), vec!["base_beep"]);
+    }
+
+    #[test]
+    fn unmapped_event_is_silent_when_configured() {
+        let engine = RulesEngine::new();
+        assert!(engine.resolve_with_base_beep(&event(), BaseBeepBehavior::Silent).is_empty());
+    }
+
+    #[test]
+    fn mapped_event_never_falls_back_to_the_beep() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![Rule { name: "r".into(), event_kind: "ping".into(), pool: "danger".into(), ..Default::default() }],
+        });
+        assert_eq!(engine.resolve_with_base_beep(&event(), BaseBeepBehavior::Beep), vec!["danger"]);
+    }
+}
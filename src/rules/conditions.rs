@@ -0,0 +1,58 @@
+/// A rule condition matching on which champion triggered an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChampionCondition {
+    pub champions: Vec<String>,
+    /// Whether this condition can match the local player at all. Some
+    /// rules are meant purely for "watch out, an enemy X is near" and
+    /// should never fire off the local player's own actions; others are
+    /// meant to say "when I'm playing one of these champions."
+    pub include_local_player_champion: bool,
+}
+
+/// A player involved in an event, as seen by a [`ChampionCondition`].
+pub struct PlayerContext<'a> {
+    pub champion: &'a str,
+    pub is_local_player: bool,
+}
+
+impl ChampionCondition {
+    pub fn matches(&self, player: &PlayerContext) -> bool {
+        if player.is_local_player && !self.include_local_player_champion {
+            return false;
+        }
+        self.champions.iter().any(|c| c == player.champion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(include_local_player_champion: bool) -> ChampionCondition {
+        ChampionCondition { champions: vec!["Ahri".into()], include_local_player_champion }
+    }
+
+    #[test]
+    fn matches_a_non_local_player_on_the_listed_champion() {
+        let player = PlayerContext { champion: "Ahri", is_local_player: false };
+        assert!(condition(false).matches(&player));
+    }
+
+    #[test]
+    fn excludes_the_local_player_by_default() {
+        let player = PlayerContext { champion: "Ahri", is_local_player: true };
+        assert!(!condition(false).matches(&player));
+    }
+
+    #[test]
+    fn includes_the_local_player_when_configured() {
+        let player = PlayerContext { champion: "Ahri", is_local_player: true };
+        assert!(condition(true).matches(&player));
+    }
+
+    #[test]
+    fn never_matches_an_unlisted_champion() {
+        let player = PlayerContext { champion: "Zed", is_local_player: false };
+        assert!(!condition(true).matches(&player));
+    }
+}
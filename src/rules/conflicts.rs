@@ -0,0 +1,185 @@
+use super::player_match::PlayerPatternKind;
+use super::RulesEngine;
+
+/// Two (or more) enabled rules with the same event kind and the same
+/// player-name condition, but different pools — both will fire together,
+/// which is usually an authoring mistake rather than intentional layering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleConflict {
+    pub event_kind: String,
+    pub rule_names: Vec<String>,
+}
+
+/// The condition a rule actually matches on, beyond its event kind: which
+/// player (if any) it's restricted to. Two rules only conflict if their
+/// condition sets are the same — different player patterns mean they can
+/// never both fire for the same poll.
+type ConditionKey<'a> = (&'a str, Option<(PlayerPatternKind, &'a str)>);
+
+impl RulesEngine {
+    /// Find rules in *enabled* packs that are themselves *enabled*, share an
+    /// event kind and player-name condition, but resolve to different
+    /// pools.
+    pub fn find_conflicts(&self) -> Vec<RuleConflict> {
+        let mut by_condition: std::collections::HashMap<ConditionKey, Vec<(&str, &str)>> =
+            std::collections::HashMap::new();
+        for pack in self.packs.iter().filter(|p| p.enabled) {
+            for rule in pack.rules.iter().filter(|r| r.enabled) {
+                let condition = (
+                    rule.event_kind.as_str(),
+                    rule.player_pattern_kind.zip(rule.player_pattern.as_deref()),
+                );
+                by_condition.entry(condition).or_default().push((rule.name.as_str(), rule.pool.as_str()));
+            }
+        }
+
+        let mut conflicts: Vec<RuleConflict> = by_condition
+            .into_iter()
+            .filter_map(|((event_kind, _), bindings)| {
+                let distinct_pools: std::collections::BTreeSet<&str> =
+                    bindings.iter().map(|(_, pool)| *pool).collect();
+                if distinct_pools.len() > 1 {
+                    Some(RuleConflict {
+                        event_kind: event_kind.to_string(),
+                        rule_names: bindings.into_iter().map(|(name, _)| name.to_string()).collect(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+        conflicts.sort_by(|a, b| a.event_kind.cmp(&b.event_kind));
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{Rule, RulesPack};
+
+    #[test]
+    fn no_conflict_when_rules_share_a_pool() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![
+                Rule { name: "a".into(), event_kind: "ping".into(), pool: "danger".into(), ..Default::default() },
+                Rule { name: "b".into(), event_kind: "ping".into(), pool: "danger".into(), ..Default::default() },
+            ],
+        });
+        assert!(engine.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn conflict_when_same_event_kind_maps_to_different_pools() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![
+                Rule { name: "a".into(), event_kind: "ping".into(), pool: "danger".into(), ..Default::default() },
+                Rule { name: "b".into(), event_kind: "ping".into(), pool: "retreat".into(), ..Default::default() },
+            ],
+        });
+        let conflicts = engine.find_conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].event_kind, "ping");
+    }
+
+    #[test]
+    fn disabled_packs_are_ignored() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: false,
+            rules: vec![
+                Rule { name: "a".into(), event_kind: "ping".into(), pool: "danger".into(), ..Default::default() },
+                Rule { name: "b".into(), event_kind: "ping".into(), pool: "retreat".into(), ..Default::default() },
+            ],
+        });
+        assert!(engine.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn a_disabled_rule_does_not_count_toward_a_conflict() {
+        let mut engine = RulesEngine::new();
+        let mut disabled = Rule {
+            name: "b".into(),
+            event_kind: "ping".into(),
+            pool: "retreat".into(),
+            ..Default::default()
+        };
+        disabled.enabled = false;
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![
+                Rule { name: "a".into(), event_kind: "ping".into(), pool: "danger".into(), ..Default::default() },
+                disabled,
+            ],
+        });
+        assert!(engine.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn non_overlapping_player_patterns_on_the_same_event_kind_do_not_conflict() {
+        use crate::rules::player_match::PlayerPatternKind;
+
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![
+                Rule {
+                    name: "a".into(),
+                    event_kind: "ping".into(),
+                    pool: "danger".into(),
+                    player_pattern_kind: Some(PlayerPatternKind::Wildcard),
+                    player_pattern: Some("Faker".into()),
+                    ..Default::default()
+                },
+                Rule {
+                    name: "b".into(),
+                    event_kind: "ping".into(),
+                    pool: "retreat".into(),
+                    player_pattern_kind: Some(PlayerPatternKind::Wildcard),
+                    player_pattern: Some("Chovy".into()),
+                    ..Default::default()
+                },
+            ],
+        });
+        assert!(engine.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn the_same_player_pattern_on_the_same_event_kind_still_conflicts() {
+        use crate::rules::player_match::PlayerPatternKind;
+
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![
+                Rule {
+                    name: "a".into(),
+                    event_kind: "ping".into(),
+                    pool: "danger".into(),
+                    player_pattern_kind: Some(PlayerPatternKind::Wildcard),
+                    player_pattern: Some("Faker".into()),
+                    ..Default::default()
+                },
+                Rule {
+                    name: "b".into(),
+                    event_kind: "ping".into(),
+                    pool: "retreat".into(),
+                    player_pattern_kind: Some(PlayerPatternKind::Wildcard),
+                    player_pattern: Some("Faker".into()),
+                    ..Default::default()
+                },
+            ],
+        });
+        assert_eq!(engine.find_conflicts().len(), 1);
+    }
+}
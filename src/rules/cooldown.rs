@@ -0,0 +1,61 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Tracks the last time each cooldown-gated key (a rule's name) fired, so
+/// [`super::RulesEngine::matching_rule_names_respecting_cooldown`] can skip
+/// anything that fired too recently. Wrapped in a `RefCell` since the engine
+/// only holds `&self` when evaluating events.
+#[derive(Debug, Default)]
+pub struct CooldownTracker {
+    last_fired: RefCell<HashMap<String, Instant>>,
+}
+
+impl CooldownTracker {
+    pub fn new() -> Self {
+        CooldownTracker::default()
+    }
+
+    /// Whether `key` fired within the last `cooldown` as of `now`.
+    pub fn is_on_cooldown(&self, key: &str, cooldown: Duration, now: Instant) -> bool {
+        self.last_fired
+            .borrow()
+            .get(key)
+            .is_some_and(|&last| now.saturating_duration_since(last) < cooldown)
+    }
+
+    /// Record that `key` fired at `now`, starting its cooldown window.
+    pub fn record_fire(&self, key: &str, now: Instant) {
+        self.last_fired.borrow_mut().insert(key.to_string(), now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_key_that_has_never_fired_is_not_on_cooldown() {
+        let tracker = CooldownTracker::new();
+        assert!(!tracker.is_on_cooldown("penta", Duration::from_secs(5), Instant::now()));
+    }
+
+    #[test]
+    fn a_key_is_on_cooldown_immediately_after_firing() {
+        let tracker = CooldownTracker::new();
+        let now = Instant::now();
+        tracker.record_fire("penta", now);
+
+        assert!(tracker.is_on_cooldown("penta", Duration::from_secs(5), now));
+    }
+
+    #[test]
+    fn a_key_leaves_cooldown_once_the_window_elapses() {
+        let tracker = CooldownTracker::new();
+        let now = Instant::now();
+        tracker.record_fire("penta", now);
+
+        let later = now + Duration::from_secs(10);
+        assert!(!tracker.is_on_cooldown("penta", Duration::from_secs(5), later));
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use super::RulesEngine;
+use crate::game::GameEvent;
+
+/// A recorded game's events, replayed in order against a pack under test.
+pub type RecordedGame = Vec<GameEvent>;
+
+/// How many times each rule fired across a corpus, keyed by rule name.
+pub type FireCountReport = HashMap<String, u32>;
+
+/// Replay every game in `corpus` through `engine` headlessly (no sound is
+/// ever selected or played) and tally how many times each rule fired, so
+/// pack authors can spot rules that never fire (dead weight) or fire on
+/// nearly every event (too eager to be useful).
+pub fn test_pack_against_corpus(engine: &RulesEngine, corpus: &[RecordedGame]) -> FireCountReport {
+    let mut report = FireCountReport::new();
+    for game in corpus {
+        for event in game {
+            for rule_name in engine.matching_rule_names(event) {
+                *report.entry(rule_name.to_string()).or_insert(0) += 1;
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events::PingKind;
+    use crate::rules::{Rule, RulesPack};
+
+    fn engine_with(rules: Vec<Rule>) -> RulesEngine {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack { name: "pack".into(), enabled: true, rules });
+        engine
+    }
+
+    #[test]
+    fn reports_fire_counts_for_one_bundled_recorded_game() {
+        let engine = engine_with(vec![
+            Rule { name: "danger".into(), event_kind: "ping".into(), pool: "danger_pool".into(), ..Default::default() },
+            Rule { name: "never_fires".into(), event_kind: "surrender_vote_called".into(), pool: "vote_pool".into(), ..Default::default() },
+        ]);
+        let corpus = vec![vec![
+            GameEvent::Ping { kind: PingKind::Danger, from_local_player: true },
+            GameEvent::Ping { kind: PingKind::OnMyWay, from_local_player: false },
+            GameEvent::ItemComplete { item_name: "Infinity Edge".to_string() },
+        ]];
+
+        let report = test_pack_against_corpus(&engine, &corpus);
+
+        assert_eq!(report.get("danger"), Some(&2));
+        assert_eq!(report.get("never_fires"), None);
+    }
+
+    #[test]
+    fn tallies_fire_counts_across_every_game_in_the_corpus() {
+        let engine = engine_with(vec![Rule {
+            name: "danger".into(),
+            event_kind: "ping".into(),
+            pool: "danger_pool".into(),
+            ..Default::default()
+        }]);
+        let ping = || GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        let corpus = vec![vec![ping()], vec![ping(), ping()]];
+
+        let report = test_pack_against_corpus(&engine, &corpus);
+
+        assert_eq!(report.get("danger"), Some(&3));
+    }
+
+    #[test]
+    fn an_empty_corpus_reports_nothing() {
+        let engine = engine_with(vec![Rule {
+            name: "danger".into(),
+            event_kind: "ping".into(),
+            pool: "danger_pool".into(),
+            ..Default::default()
+        }]);
+        assert!(test_pack_against_corpus(&engine, &[]).is_empty());
+    }
+}
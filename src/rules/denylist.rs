@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use crate::game::GameEvent;
+
+/// Event kinds (see [`GameEvent::kind_name`]) that should never be
+/// forwarded to the rules engine, regardless of what any pack binds them
+/// to — e.g. a user who finds ping spam annoying can deny "ping" outright.
+#[derive(Debug, Clone, Default)]
+pub struct EventDenylist {
+    denied_kinds: HashSet<String>,
+}
+
+impl EventDenylist {
+    pub fn new(denied_kinds: impl IntoIterator<Item = String>) -> Self {
+        EventDenylist { denied_kinds: denied_kinds.into_iter().collect() }
+    }
+
+    pub fn is_denied(&self, event: &GameEvent) -> bool {
+        self.denied_kinds.contains(event.kind_name())
+    }
+
+    /// Filter `events`, dropping any whose kind is denylisted.
+    pub fn filter(&self, events: Vec<GameEvent>) -> Vec<GameEvent> {
+        events.into_iter().filter(|e| !self.is_denied(e)).collect()
+    }
+}
+
+/// Inverse of [`EventDenylist`]: when `only_these_events` is set, only the
+/// listed event kinds are forwarded and everything else is dropped — for
+/// users who want a very quiet setup rather than denylisting noise one
+/// kind at a time.
+#[derive(Debug, Clone, Default)]
+pub struct EventAllowlist {
+    only_these_events: Option<HashSet<String>>,
+}
+
+impl EventAllowlist {
+    /// An allowlist restricted to `allowed_kinds`. Passing an empty
+    /// iterator still allowlists (nothing will pass) — to allow everything,
+    /// use [`EventAllowlist::default`] instead.
+    pub fn new(allowed_kinds: impl IntoIterator<Item = String>) -> Self {
+        EventAllowlist { only_these_events: Some(allowed_kinds.into_iter().collect()) }
+    }
+
+    pub fn is_allowed(&self, event: &GameEvent) -> bool {
+        match &self.only_these_events {
+            Some(allowed) => allowed.contains(event.kind_name()),
+            None => true,
+        }
+    }
+
+    /// Filter `events`, dropping any not in the allowlist (a no-op filter
+    /// if no allowlist is configured).
+    pub fn filter(&self, events: Vec<GameEvent>) -> Vec<GameEvent> {
+        events.into_iter().filter(|e| self.is_allowed(e)).collect()
+    }
+}
+
+/// A one-toggle "only callouts involving me" mode, composing what would
+/// otherwise be several separate ally/local filters (pings from the local
+/// player, objectives the local team took) into a single switch.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SoloMode {
+    pub enabled: bool,
+}
+
+impl SoloMode {
+    pub fn new(enabled: bool) -> Self {
+        SoloMode { enabled }
+    }
+
+    /// Whether `event` directly involves the local player or their team,
+    /// so it's still worth a callout with solo mode on. Events with no
+    /// notion of "involves me" (e.g. a surrender vote) are dropped.
+    pub fn is_allowed(&self, event: &GameEvent) -> bool {
+        if !self.enabled {
+            return true;
+        }
+        match event {
+            GameEvent::Ping { from_local_player, .. } => *from_local_player,
+            GameEvent::ObjectiveTaken { team, .. } => *team == crate::game::Team::Ally,
+            GameEvent::ItemComplete { .. } => true,
+            GameEvent::ObjectiveSpawn { .. }
+            | GameEvent::SurrenderVoteCalled { .. }
+            | GameEvent::ObjectiveSmited { .. }
+            | GameEvent::Comeback { .. } => false,
+        }
+    }
+
+    /// Filter `events` down to the ones solo mode would still announce
+    /// (a no-op filter when solo mode is disabled).
+    pub fn filter(&self, events: Vec<GameEvent>) -> Vec<GameEvent> {
+        events.into_iter().filter(|e| self.is_allowed(e)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events::PingKind;
+
+    #[test]
+    fn denied_events_are_dropped() {
+        let denylist = EventDenylist::new(["ping".to_string()]);
+        let event = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        assert!(denylist.is_denied(&event));
+        assert!(denylist.filter(vec![event]).is_empty());
+    }
+
+    #[test]
+    fn non_denied_events_pass_through() {
+        let denylist = EventDenylist::new(["surrender_vote_called".to_string()]);
+        let event = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        assert!(!denylist.is_denied(&event));
+        assert_eq!(denylist.filter(vec![event.clone()]), vec![event]);
+    }
+
+    #[test]
+    fn allowlisted_events_pass_through() {
+        let allowlist = EventAllowlist::new(["ping".to_string()]);
+        let event = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        assert!(allowlist.is_allowed(&event));
+        assert_eq!(allowlist.filter(vec![event.clone()]), vec![event]);
+    }
+
+    #[test]
+    fn events_not_on_the_allowlist_are_dropped() {
+        let allowlist = EventAllowlist::new(["surrender_vote_called".to_string()]);
+        let event = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        assert!(!allowlist.is_allowed(&event));
+        assert!(allowlist.filter(vec![event]).is_empty());
+    }
+
+    #[test]
+    fn no_allowlist_configured_passes_everything_through() {
+        let allowlist = EventAllowlist::default();
+        let event = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        assert!(allowlist.is_allowed(&event));
+    }
+
+    #[test]
+    fn disabled_solo_mode_passes_everything_through() {
+        let solo_mode = SoloMode::new(false);
+        let event = GameEvent::SurrenderVoteCalled { votes_for: 3, votes_needed: 5 };
+        assert!(solo_mode.is_allowed(&event));
+    }
+
+    #[test]
+    fn solo_mode_keeps_pings_from_the_local_player() {
+        let solo_mode = SoloMode::new(true);
+        let mine = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        let teammates = GameEvent::Ping { kind: PingKind::Danger, from_local_player: false };
+        assert!(solo_mode.is_allowed(&mine));
+        assert!(!solo_mode.is_allowed(&teammates));
+    }
+
+    #[test]
+    fn solo_mode_keeps_objectives_the_local_team_took() {
+        use crate::game::objectives::Objective;
+        use crate::game::Team;
+
+        let solo_mode = SoloMode::new(true);
+        let ours = GameEvent::ObjectiveTaken { objective: Objective::Baron, team: Team::Ally };
+        let theirs = GameEvent::ObjectiveTaken { objective: Objective::Baron, team: Team::Enemy };
+        assert!(solo_mode.is_allowed(&ours));
+        assert!(!solo_mode.is_allowed(&theirs));
+    }
+
+    #[test]
+    fn solo_mode_drops_events_with_no_notion_of_involving_me() {
+        use crate::game::objectives::Objective;
+
+        let solo_mode = SoloMode::new(true);
+        assert!(!solo_mode.is_allowed(&GameEvent::SurrenderVoteCalled { votes_for: 3, votes_needed: 5 }));
+        assert!(!solo_mode.is_allowed(&GameEvent::ObjectiveSpawn { objective: Objective::Baron, game_time: 1200.0 }));
+        assert!(!solo_mode.is_allowed(&GameEvent::ObjectiveSmited {
+            objective: Objective::Baron,
+            stolen_by_enemy: true,
+            contesting_team: None,
+            was_close: false,
+        }));
+        assert!(!solo_mode.is_allowed(&GameEvent::Comeback { gold_deficit: 1000, kill_deficit: 2 }));
+    }
+
+    #[test]
+    fn solo_mode_keeps_item_completions_since_theyre_always_the_local_players() {
+        let solo_mode = SoloMode::new(true);
+        assert!(solo_mode.is_allowed(&GameEvent::ItemComplete { item_name: "Infinity Edge".to_string() }));
+    }
+
+    #[test]
+    fn filter_drops_non_self_events_across_a_mixed_batch() {
+        use crate::game::objectives::Objective;
+        use crate::game::Team;
+
+        let solo_mode = SoloMode::new(true);
+        let mine = GameEvent::Ping { kind: PingKind::Danger, from_local_player: true };
+        let theirs = GameEvent::ObjectiveTaken { objective: Objective::Baron, team: Team::Enemy };
+        let vote = GameEvent::SurrenderVoteCalled { votes_for: 3, votes_needed: 5 };
+
+        let filtered = solo_mode.filter(vec![mine.clone(), theirs, vote]);
+
+        assert_eq!(filtered, vec![mine]);
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Rule, RulesPack};
+use crate::sound::SoundPack;
+
+/// A sound referenced by an exported rule, carried along so the snippet is
+/// self-contained (the importer doesn't need the exporter's pack on hand).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetSound {
+    pub id: String,
+    pub source_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetEntry {
+    pub rule: Rule,
+    pub sounds: Vec<SnippetSound>,
+}
+
+/// A shareable export of a handful of rules, independent of the rest of
+/// whatever pack they came from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleSnippet {
+    pub entries: Vec<SnippetEntry>,
+}
+
+/// Export the rules in `pack` named in `rule_ids`, along with the sounds in
+/// each rule's target pool, as a JSON snippet suitable for pasting to
+/// another user.
+pub fn export_rules(pack: &RulesPack, sound_pack: &SoundPack, rule_ids: &[String]) -> String {
+    let entries = pack
+        .rules
+        .iter()
+        .filter(|r| rule_ids.contains(&r.name))
+        .map(|rule| {
+            let sounds = sound_pack
+                .pools
+                .get(&rule.pool)
+                .map(|pool| {
+                    pool.sounds()
+                        .iter()
+                        .map(|s| SnippetSound {
+                            id: s.id.clone(),
+                            source_url: s.source_url.clone(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            SnippetEntry { rule: rule.clone(), sounds }
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&RuleSnippet { entries })
+        .expect("serializing a rule snippet cannot fail")
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("not valid rule snippet JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// Merge the rules in a snippet produced by [`export_rules`] into `pack`,
+/// renaming any rule whose name collides with one already present so both
+/// survive (the sounds aren't re-imported into the sound pack — callers
+/// needing those back reference the snippet's `sounds` directly).
+pub fn import_rules(pack: &mut RulesPack, json: &str) -> Result<(), ImportError> {
+    let snippet: RuleSnippet = serde_json::from_str(json)?;
+    for entry in snippet.entries {
+        let mut rule = entry.rule;
+        while pack.rules.iter().any(|r| r.name == rule.name) {
+            rule.name.push_str("_imported");
+        }
+        pack.rules.push(rule);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn sound_pack(name: &str) -> SoundPack {
+        let dir = std::env::temp_dir().join(format!("scout-export-test-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sound-pack.json");
+        let mut f = std::fs::File::create(&path).unwrap();
+        f.write_all(
+            br#"{"id": "p", "pools": {"kill": {"mode": "random", "sounds": [{"id": "a", "url": "https://example.com/a"}]}}}"#,
+        )
+        .unwrap();
+        SoundPack::load(&path).unwrap()
+    }
+
+    fn rules_pack() -> RulesPack {
+        RulesPack {
+            name: "mine".into(),
+            enabled: true,
+            rules: vec![Rule { name: "on_kill".into(), event_kind: "kill".into(), pool: "kill".into(), ..Default::default() }],
+        }
+    }
+
+    #[test]
+    fn exports_the_selected_rule_with_its_sounds() {
+        let pack = rules_pack();
+        let sound_pack = sound_pack("exports_the_selected_rule_with_its_sounds");
+        let json = export_rules(&pack, &sound_pack, &["on_kill".to_string()]);
+        assert!(json.contains("on_kill"));
+        assert!(json.contains("https://example.com/a"));
+    }
+
+    #[test]
+    fn import_adds_a_new_rule() {
+        let pack = rules_pack();
+        let sound_pack = sound_pack("import_adds_a_new_rule");
+        let json = export_rules(&pack, &sound_pack, &["on_kill".to_string()]);
+
+        let mut target = RulesPack { name: "theirs".into(), enabled: true, rules: vec![] };
+        import_rules(&mut target, &json).unwrap();
+
+        assert_eq!(target.rules.len(), 1);
+        assert_eq!(target.rules[0].name, "on_kill");
+    }
+
+    #[test]
+    fn import_renames_on_name_collision() {
+        let pack = rules_pack();
+        let sound_pack = sound_pack("import_renames_on_name_collision");
+        let json = export_rules(&pack, &sound_pack, &["on_kill".to_string()]);
+
+        let mut target = rules_pack();
+        import_rules(&mut target, &json).unwrap();
+
+        assert_eq!(target.rules.len(), 2);
+        assert_eq!(target.rules[1].name, "on_kill_imported");
+    }
+}
@@ -0,0 +1,324 @@
+pub mod base_beep;
+pub mod conditions;
+pub mod conflicts;
+pub mod cooldown;
+pub mod corpus;
+pub mod denylist;
+pub mod export;
+pub mod mute;
+pub mod objective_defaults;
+pub mod player_match;
+pub mod simple_mapping;
+pub mod simulate;
+pub mod stats;
+pub mod validate;
+
+use std::time::Instant;
+
+use crate::game::GameEvent;
+use serde::{Deserialize, Serialize};
+
+pub use base_beep::BaseBeepBehavior;
+pub use conditions::{ChampionCondition, PlayerContext};
+pub use conflicts::RuleConflict;
+pub use cooldown::CooldownTracker;
+pub use corpus::{test_pack_against_corpus, FireCountReport, RecordedGame};
+pub use denylist::{EventAllowlist, EventDenylist, SoloMode};
+pub use export::{export_rules, import_rules, ImportError};
+pub use mute::MutedPlayers;
+pub use objective_defaults::ObjectiveAnnouncementDefaults;
+pub use player_match::{PlayerMatchError, PlayerNameMatcher, PlayerPatternKind};
+pub use simple_mapping::SimpleMapping;
+pub use simulate::{simulate_selection, SelectionContext, SelectionResult, SimulationError};
+pub use stats::RuleStats;
+pub use validate::validate_rule_patterns;
+
+/// A single rule: when an event of kind `event_kind` fires, if the
+/// condition matches, play the named sound pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    pub name: String,
+    pub event_kind: String,
+    pub pool: String,
+    /// Whether this rule currently fires, independent of whether its pack
+    /// is enabled. Defaults to `true` so existing saved rules keep working.
+    #[serde(default = "default_rule_enabled")]
+    pub enabled: bool,
+    /// Free-form labels (e.g. "meme") so large packs can be bulk-toggled
+    /// with [`RulesPack::set_rules_enabled_by_tag`] instead of one at a time.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The kind of pattern `player_pattern` is, if this rule is restricted
+    /// to a specific player. Both must be set together; see
+    /// [`validate_rule_patterns`].
+    #[serde(default)]
+    pub player_pattern_kind: Option<PlayerPatternKind>,
+    #[serde(default)]
+    pub player_pattern: Option<String>,
+    /// Minimum time that must pass between consecutive fires of this rule,
+    /// enforced via [`RulesEngine::matching_rule_names_respecting_cooldown`]
+    /// and a [`CooldownTracker`]. `None` means no cooldown.
+    #[serde(default)]
+    pub cooldown_ms: Option<u64>,
+}
+
+fn default_rule_enabled() -> bool {
+    true
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule {
+            name: String::new(),
+            event_kind: String::new(),
+            pool: String::new(),
+            enabled: true,
+            tags: Vec::new(),
+            player_pattern_kind: None,
+            player_pattern: None,
+            cooldown_ms: None,
+        }
+    }
+}
+
+/// A named collection of rules that can be toggled as a unit, independent
+/// of whether any individual rule in it is enabled.
+#[derive(Debug, Clone)]
+pub struct RulesPack {
+    pub name: String,
+    pub enabled: bool,
+    pub rules: Vec<Rule>,
+}
+
+impl RulesPack {
+    /// Bulk-toggle every rule tagged `tag`, leaving untagged or
+    /// differently-tagged rules untouched. Lets users turn off, say, all
+    /// "meme" rules in a large pack at once.
+    pub fn set_rules_enabled_by_tag(&mut self, tag: &str, enabled: bool) {
+        for rule in self.rules.iter_mut() {
+            if rule.tags.iter().any(|t| t == tag) {
+                rule.enabled = enabled;
+            }
+        }
+    }
+}
+
+/// Evaluates events against whatever [`RulesPack`]s are loaded.
+#[derive(Debug, Clone, Default)]
+pub struct RulesEngine {
+    pub packs: Vec<RulesPack>,
+}
+
+impl RulesEngine {
+    pub fn new() -> Self {
+        RulesEngine::default()
+    }
+
+    /// All pools bound to `event`'s kind, from packs that are currently
+    /// enabled. Real condition matching is layered on top of this in later
+    /// rules; for now a rule fires whenever its event kind matches.
+    pub fn matching_pools(&self, event: &GameEvent) -> Vec<&str> {
+        self.packs
+            .iter()
+            .filter(|p| p.enabled)
+            .flat_map(|p| p.rules.iter())
+            .filter(|r| r.enabled && r.event_kind == event.kind_name())
+            .map(|r| r.pool.as_str())
+            .collect()
+    }
+
+    /// Names of every rule that fires for `event`, from packs that are
+    /// currently enabled — the same matching as [`Self::matching_pools`],
+    /// but keyed by rule rather than pool, for callers that need to
+    /// attribute a fire back to a specific rule (e.g. fire-count reports).
+    pub fn matching_rule_names(&self, event: &GameEvent) -> Vec<&str> {
+        self.packs
+            .iter()
+            .filter(|p| p.enabled)
+            .flat_map(|p| p.rules.iter())
+            .filter(|r| r.enabled && r.event_kind == event.kind_name())
+            .map(|r| r.name.as_str())
+            .collect()
+    }
+
+    /// Like [`Self::matching_rule_names`], but skips any rule whose
+    /// `cooldown_ms` window hasn't elapsed since it last fired (per
+    /// `cooldowns`), and records a fresh fire time for whatever does match.
+    /// Lets a penta-kill-to-airhorn binding ignore rapid repeats instead of
+    /// retriggering on every poll. If every rule for an event is on
+    /// cooldown, this simply returns an empty list, matching how other
+    /// pack-level filters fall through to defaults upstream.
+    pub fn matching_rule_names_respecting_cooldown(
+        &self,
+        event: &GameEvent,
+        cooldowns: &CooldownTracker,
+        now: Instant,
+    ) -> Vec<&str> {
+        self.packs
+            .iter()
+            .filter(|p| p.enabled)
+            .flat_map(|p| p.rules.iter())
+            .filter(|r| r.enabled && r.event_kind == event.kind_name())
+            .filter(|r| {
+                !r.cooldown_ms
+                    .is_some_and(|ms| cooldowns.is_on_cooldown(&r.name, std::time::Duration::from_millis(ms), now))
+            })
+            .map(|r| {
+                cooldowns.record_fire(&r.name, now);
+                r.name.as_str()
+            })
+            .collect()
+    }
+
+    /// List every event kind with the (rule-pack, pool) bindings currently
+    /// registered for it, regardless of whether the pack is enabled — used
+    /// to show users the full picture of what's bound to what.
+    pub fn list_bindings(&self) -> Vec<(String, Vec<(String, String)>)> {
+        let mut by_kind: std::collections::BTreeMap<String, Vec<(String, String)>> =
+            std::collections::BTreeMap::new();
+        for pack in &self.packs {
+            for rule in &pack.rules {
+                by_kind
+                    .entry(rule.event_kind.clone())
+                    .or_default()
+                    .push((pack.name.clone(), rule.pool.clone()));
+            }
+        }
+        by_kind.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::events::PingKind;
+
+    fn event() -> GameEvent {
+        GameEvent::Ping { kind: PingKind::Danger, from_local_player: true }
+    }
+
+    fn rule(pool: &str) -> Rule {
+        Rule { name: "danger".into(), event_kind: "ping".into(), pool: pool.into(), ..Default::default() }
+    }
+
+    #[test]
+    fn disabled_pack_contributes_no_rules() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: false, rules: vec![rule("danger_pool")] });
+        assert!(engine.matching_pools(&event()).is_empty());
+    }
+
+    #[test]
+    fn enabled_pack_contributes_its_rules() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: true, rules: vec![rule("danger_pool")] });
+        assert_eq!(engine.matching_pools(&event()), vec!["danger_pool"]);
+    }
+
+    #[test]
+    fn rules_only_fire_for_their_own_event_kind() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "memes".into(),
+            enabled: true,
+            rules: vec![Rule {
+                name: "spawn".into(),
+                event_kind: "objective_spawn".into(),
+                pool: "spawn_pool".into(),
+                ..Default::default()
+            }],
+        });
+        assert!(engine.matching_pools(&event()).is_empty());
+    }
+
+    #[test]
+    fn disabled_rule_does_not_contribute_even_if_its_pack_is_enabled() {
+        let mut engine = RulesEngine::new();
+        let mut disabled_rule = rule("danger_pool");
+        disabled_rule.enabled = false;
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: true, rules: vec![disabled_rule] });
+        assert!(engine.matching_pools(&event()).is_empty());
+    }
+
+    #[test]
+    fn matching_rule_names_reports_the_rule_not_the_pool() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: true, rules: vec![rule("danger_pool")] });
+        assert_eq!(engine.matching_rule_names(&event()), vec!["danger"]);
+    }
+
+    #[test]
+    fn a_rule_on_cooldown_is_skipped_on_a_rapid_repeat() {
+        let mut engine = RulesEngine::new();
+        let mut cooling = rule("danger_pool");
+        cooling.cooldown_ms = Some(5_000);
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: true, rules: vec![cooling] });
+
+        let cooldowns = CooldownTracker::new();
+        let now = Instant::now();
+
+        assert_eq!(engine.matching_rule_names_respecting_cooldown(&event(), &cooldowns, now), vec!["danger"]);
+        assert!(engine.matching_rule_names_respecting_cooldown(&event(), &cooldowns, now).is_empty());
+    }
+
+    #[test]
+    fn a_rule_fires_again_once_its_cooldown_elapses() {
+        let mut engine = RulesEngine::new();
+        let mut cooling = rule("danger_pool");
+        cooling.cooldown_ms = Some(5_000);
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: true, rules: vec![cooling] });
+
+        let cooldowns = CooldownTracker::new();
+        let now = Instant::now();
+        engine.matching_rule_names_respecting_cooldown(&event(), &cooldowns, now);
+
+        let later = now + std::time::Duration::from_secs(10);
+        assert_eq!(engine.matching_rule_names_respecting_cooldown(&event(), &cooldowns, later), vec!["danger"]);
+    }
+
+    #[test]
+    fn a_rule_without_a_cooldown_fires_every_time() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack { name: "memes".into(), enabled: true, rules: vec![rule("danger_pool")] });
+
+        let cooldowns = CooldownTracker::new();
+        let now = Instant::now();
+
+        assert_eq!(engine.matching_rule_names_respecting_cooldown(&event(), &cooldowns, now), vec!["danger"]);
+        assert_eq!(engine.matching_rule_names_respecting_cooldown(&event(), &cooldowns, now), vec!["danger"]);
+    }
+
+    #[test]
+    fn set_rules_enabled_by_tag_only_toggles_matching_rules() {
+        let mut tagged = rule("danger_pool");
+        tagged.name = "tagged".into();
+        tagged.tags = vec!["meme".into()];
+        let mut untagged = rule("other_pool");
+        untagged.name = "untagged".into();
+        let mut pack = RulesPack { name: "memes".into(), enabled: true, rules: vec![tagged, untagged] };
+
+        pack.set_rules_enabled_by_tag("meme", false);
+
+        assert!(!pack.rules.iter().find(|r| r.name == "tagged").unwrap().enabled);
+        assert!(pack.rules.iter().find(|r| r.name == "untagged").unwrap().enabled);
+    }
+
+    #[test]
+    fn list_bindings_groups_by_event_kind_across_packs() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack { name: "pack-a".into(), enabled: true, rules: vec![rule("danger_pool")] });
+        engine.packs.push(RulesPack { name: "pack-b".into(), enabled: false, rules: vec![rule("other_pool")] });
+
+        let bindings = engine.list_bindings();
+        assert_eq!(
+            bindings,
+            vec![(
+                "ping".to_string(),
+                vec![
+                    ("pack-a".to_string(), "danger_pool".to_string()),
+                    ("pack-b".to_string(), "other_pool".to_string()),
+                ]
+            )]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+/// Suppresses callouts tied to specific players, independent of whatever
+/// rules would otherwise fire — applied in the forwarding/playback path
+/// after rule matching, not as a rule condition itself.
+#[derive(Debug, Clone, Default)]
+pub struct MutedPlayers {
+    pub muted: Vec<String>,
+}
+
+impl MutedPlayers {
+    pub fn new(muted: Vec<String>) -> Self {
+        MutedPlayers { muted }
+    }
+
+    /// Case-insensitive, Riot-ID-aware: a mute entry of `"Name"` matches
+    /// `"Name#TAG"` and vice versa, since players rarely know (or type)
+    /// each other's tag.
+    pub fn is_muted(&self, player: &str) -> bool {
+        let name = riot_id_name(player);
+        self.muted.iter().any(|m| riot_id_name(m).eq_ignore_ascii_case(name))
+    }
+
+    /// Suppress a kill callout if either participant is muted.
+    pub fn should_suppress_kill(&self, killer: &str, victim: &str) -> bool {
+        self.is_muted(killer) || self.is_muted(victim)
+    }
+}
+
+fn riot_id_name(id: &str) -> &str {
+    id.split('#').next().unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutes_a_player_by_exact_name() {
+        let muted = MutedPlayers::new(vec!["Faker".into()]);
+        assert!(muted.is_muted("Faker"));
+    }
+
+    #[test]
+    fn mute_matching_is_case_insensitive() {
+        let muted = MutedPlayers::new(vec!["faker".into()]);
+        assert!(muted.is_muted("FAKER"));
+    }
+
+    #[test]
+    fn mute_ignores_the_riot_id_tag_on_either_side() {
+        let muted = MutedPlayers::new(vec!["Faker#KR1".into()]);
+        assert!(muted.is_muted("Faker#NA1"));
+    }
+
+    #[test]
+    fn does_not_mute_unrelated_players() {
+        let muted = MutedPlayers::new(vec!["Faker".into()]);
+        assert!(!muted.is_muted("Chovy"));
+    }
+
+    #[test]
+    fn suppresses_a_kill_involving_a_muted_killer_but_not_unrelated_kills() {
+        let muted = MutedPlayers::new(vec!["Faker".into()]);
+        assert!(muted.should_suppress_kill("Faker", "Chovy"));
+        assert!(!muted.should_suppress_kill("Chovy", "Zeus"));
+    }
+
+    #[test]
+    fn suppresses_a_kill_involving_a_muted_victim() {
+        let muted = MutedPlayers::new(vec!["Chovy".into()]);
+        assert!(muted.should_suppress_kill("Faker", "Chovy"));
+    }
+}
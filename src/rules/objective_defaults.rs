@@ -0,0 +1,98 @@
+use super::RulesEngine;
+use crate::game::{GameEvent, Team};
+
+/// Fallback pools applied to `ObjectiveTaken` when no rule matches, so
+/// enemy objectives read as a distinct threat out of the box instead of
+/// requiring a rule authored for every objective/team combination.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectiveAnnouncementDefaults {
+    pub ally_default: String,
+    pub enemy_default: String,
+}
+
+impl Default for ObjectiveAnnouncementDefaults {
+    fn default() -> Self {
+        ObjectiveAnnouncementDefaults {
+            ally_default: "objective_taken".to_string(),
+            enemy_default: "objective_taken_warning".to_string(),
+        }
+    }
+}
+
+impl ObjectiveAnnouncementDefaults {
+    fn resolve(&self, team: Team) -> &str {
+        match team {
+            Team::Ally => &self.ally_default,
+            Team::Enemy => &self.enemy_default,
+        }
+    }
+}
+
+impl RulesEngine {
+    /// Resolve what to play for `event`: its bound pools if any rule
+    /// matches, otherwise `defaults`' ally/enemy fallback for
+    /// `ObjectiveTaken` — an ominous default for the enemy side, a plain
+    /// one for ours. Events other than `ObjectiveTaken` have no default
+    /// here and just fall through to an empty list.
+    pub fn resolve_objective_default<'a>(
+        &'a self,
+        event: &GameEvent,
+        defaults: &'a ObjectiveAnnouncementDefaults,
+    ) -> Vec<&'a str> {
+        let pools = self.matching_pools(event);
+        if !pools.is_empty() {
+            return pools;
+        }
+        match event {
+            GameEvent::ObjectiveTaken { team, .. } => vec![defaults.resolve(*team)],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::objectives::Objective;
+    use crate::rules::{Rule, RulesPack};
+
+    #[test]
+    fn enemy_objectives_resolve_to_the_warning_default() {
+        let engine = RulesEngine::new();
+        let event = GameEvent::ObjectiveTaken { objective: Objective::Baron, team: Team::Enemy };
+        assert_eq!(
+            engine.resolve_objective_default(&event, &ObjectiveAnnouncementDefaults::default()),
+            vec!["objective_taken_warning"]
+        );
+    }
+
+    #[test]
+    fn ally_objectives_resolve_to_the_normal_default() {
+        let engine = RulesEngine::new();
+        let event = GameEvent::ObjectiveTaken { objective: Objective::Baron, team: Team::Ally };
+        assert_eq!(
+            engine.resolve_objective_default(&event, &ObjectiveAnnouncementDefaults::default()),
+            vec!["objective_taken"]
+        );
+    }
+
+    #[test]
+    fn a_matching_rule_never_falls_back_to_a_default() {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "p".into(),
+            enabled: true,
+            rules: vec![Rule {
+                name: "r".into(),
+                event_kind: "objective_taken_enemy".into(),
+                pool: "custom_warning".into(),
+                ..Default::default()
+            }],
+        });
+        let event = GameEvent::ObjectiveTaken { objective: Objective::Baron, team: Team::Enemy };
+        assert_eq!(
+            engine.resolve_objective_default(&event, &ObjectiveAnnouncementDefaults::default()),
+            vec!["custom_warning"]
+        );
+    }
+}
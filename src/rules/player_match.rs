@@ -0,0 +1,84 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Which kind of pattern a [`crate::rules::Rule`]'s saved `player_pattern`
+/// is, so it can be recompiled into a [`PlayerNameMatcher`] after loading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerPatternKind {
+    Wildcard,
+    Regex,
+}
+
+/// How a rule's player-name condition matches a summoner name.
+#[derive(Debug, Clone)]
+pub enum PlayerNameMatcher {
+    Exact(String),
+    /// `*`/`?` glob-style wildcard, translated to a regex under the hood.
+    Wildcard(Regex),
+    Regex(Regex),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid regex in player name condition: {0}")]
+pub struct PlayerMatchError(#[from] regex::Error);
+
+impl PlayerNameMatcher {
+    pub fn exact(name: impl Into<String>) -> Self {
+        PlayerNameMatcher::Exact(name.into())
+    }
+
+    pub fn wildcard(pattern: &str) -> Result<Self, PlayerMatchError> {
+        let escaped = regex::escape(pattern)
+            .replace(r"\*", ".*")
+            .replace(r"\?", ".");
+        let regex = Regex::new(&format!("^{escaped}$"))?;
+        Ok(PlayerNameMatcher::Wildcard(regex))
+    }
+
+    pub fn regex(pattern: &str) -> Result<Self, PlayerMatchError> {
+        Ok(PlayerNameMatcher::Regex(Regex::new(pattern)?))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            PlayerNameMatcher::Exact(expected) => expected == name,
+            PlayerNameMatcher::Wildcard(re) | PlayerNameMatcher::Regex(re) => re.is_match(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_matcher_requires_a_full_match() {
+        let matcher = PlayerNameMatcher::exact("Faker");
+        assert!(matcher.matches("Faker"));
+        assert!(!matcher.matches("Faker2"));
+    }
+
+    #[test]
+    fn wildcard_matcher_supports_star_and_question_mark() {
+        let matcher = PlayerNameMatcher::wildcard("T1 *").unwrap();
+        assert!(matcher.matches("T1 Faker"));
+        assert!(!matcher.matches("G2 Faker"));
+
+        let single = PlayerNameMatcher::wildcard("Faker?").unwrap();
+        assert!(single.matches("Faker1"));
+        assert!(!single.matches("Faker12"));
+    }
+
+    #[test]
+    fn regex_matcher_matches_arbitrary_patterns() {
+        let matcher = PlayerNameMatcher::regex(r"^Guest\d+$").unwrap();
+        assert!(matcher.matches("Guest1234"));
+        assert!(!matcher.matches("Guest"));
+    }
+
+    #[test]
+    fn invalid_regex_is_rejected() {
+        assert!(PlayerNameMatcher::regex("(").is_err());
+    }
+}
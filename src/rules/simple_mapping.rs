@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use super::{Rule, RulesPack};
+
+/// The simplest possible event-to-sound config: one pool per event kind,
+/// edited as flat key-value pairs (e.g. `ping -> danger_pool`) rather than
+/// full [`super::RulesPack`] authoring. This is what the basic settings UI
+/// writes; it's converted into a [`RulesPack`] for the engine to evaluate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimpleMapping {
+    bindings: HashMap<String, String>,
+}
+
+impl SimpleMapping {
+    pub fn new() -> Self {
+        SimpleMapping::default()
+    }
+
+    pub fn set(&mut self, event_kind: impl Into<String>, pool: impl Into<String>) {
+        self.bindings.insert(event_kind.into(), pool.into());
+    }
+
+    pub fn get(&self, event_kind: &str) -> Option<&str> {
+        self.bindings.get(event_kind).map(String::as_str)
+    }
+
+    pub fn unset(&mut self, event_kind: &str) {
+        self.bindings.remove(event_kind);
+    }
+
+    /// One-click "use this sound for everything": bind every known event
+    /// kind to `source`, overwriting whatever each was bound to before.
+    pub fn set_uniform_sound(&mut self, source: impl Into<String>) {
+        let source = source.into();
+        for event_kind in crate::game::GameEvent::all_kind_names() {
+            self.bindings.insert(event_kind.to_string(), source.clone());
+        }
+    }
+
+    /// Convert into a [`RulesPack`] the engine can evaluate directly.
+    pub fn into_rules_pack(self, pack_name: impl Into<String>) -> RulesPack {
+        let rules = self
+            .bindings
+            .into_iter()
+            .map(|(event_kind, pool)| Rule {
+                name: format!("simple:{event_kind}"),
+                event_kind,
+                pool,
+                ..Default::default()
+            })
+            .collect();
+        RulesPack { name: pack_name.into(), enabled: true, rules }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_get_round_trip() {
+        let mut mapping = SimpleMapping::new();
+        mapping.set("ping", "danger_pool");
+        assert_eq!(mapping.get("ping"), Some("danger_pool"));
+    }
+
+    #[test]
+    fn unset_removes_a_binding() {
+        let mut mapping = SimpleMapping::new();
+        mapping.set("ping", "danger_pool");
+        mapping.unset("ping");
+        assert_eq!(mapping.get("ping"), None);
+    }
+
+    #[test]
+    fn set_uniform_sound_binds_every_event_kind_to_the_chosen_source() {
+        let mut mapping = SimpleMapping::new();
+        mapping.set_uniform_sound("single_beep");
+
+        for event_kind in crate::game::GameEvent::all_kind_names() {
+            assert_eq!(mapping.get(event_kind), Some("single_beep"));
+        }
+    }
+
+    #[test]
+    fn set_uniform_sound_overwrites_any_existing_bindings() {
+        let mut mapping = SimpleMapping::new();
+        mapping.set("ping", "danger_pool");
+        mapping.set_uniform_sound("single_beep");
+
+        assert_eq!(mapping.get("ping"), Some("single_beep"));
+    }
+
+    #[test]
+    fn converts_into_a_rules_pack() {
+        let mut mapping = SimpleMapping::new();
+        mapping.set("ping", "danger_pool");
+        let pack = mapping.into_rules_pack("simple");
+        assert_eq!(pack.rules.len(), 1);
+        assert_eq!(pack.rules[0].event_kind, "ping");
+        assert_eq!(pack.rules[0].pool, "danger_pool");
+    }
+}
@@ -0,0 +1,110 @@
+use serde::Deserialize;
+
+use super::RulesEngine;
+use crate::game::GameEvent;
+use crate::sound::SoundPack;
+
+/// A full synthetic event context for [`simulate_selection`], deserialized
+/// from JSON so pack authors can test complex bindings without connecting
+/// to a live game.
+#[derive(Debug, Deserialize)]
+pub struct SelectionContext {
+    pub event: GameEvent,
+}
+
+/// What [`simulate_selection`] found for a [`SelectionContext`]: which pool
+/// (if any) the event matched, and which sound that pool picked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionResult {
+    pub matched_pool: Option<String>,
+    pub selected_sound_id: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SimulationError {
+    #[error("invalid selection context JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// Debugging harness: deserialize a [`SelectionContext`] from `context_json`,
+/// run its event through `engine`'s matching pools, and pick a sound from
+/// whichever pool matched first via `pack`. If multiple pools match, only
+/// the first is exercised — real playback has the same "first binding wins"
+/// behavior today.
+pub fn simulate_selection(
+    engine: &RulesEngine,
+    pack: &mut SoundPack,
+    context_json: &str,
+) -> Result<SelectionResult, SimulationError> {
+    let context: SelectionContext = serde_json::from_str(context_json)?;
+    let matched_pool = engine
+        .matching_pools(&context.event)
+        .first()
+        .map(|pool| pool.to_string());
+    let selected_sound_id = matched_pool
+        .as_ref()
+        .and_then(|name| pack.pools.get_mut(name))
+        .and_then(|pool| pool.select_next())
+        .map(|sound| sound.id.clone());
+
+    Ok(SelectionResult { matched_pool, selected_sound_id })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{Rule, RulesPack};
+    use crate::sound::{SelectionMode, Sound, SoundPool};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn engine_with_ping_rule() -> RulesEngine {
+        let mut engine = RulesEngine::new();
+        engine.packs.push(RulesPack {
+            name: "memes".into(),
+            enabled: true,
+            rules: vec![Rule { name: "danger".into(), event_kind: "ping".into(), pool: "danger_pool".into(), ..Default::default() }],
+        });
+        engine
+    }
+
+    fn pack_with_danger_pool() -> SoundPack {
+        let mut pools = HashMap::new();
+        pools.insert(
+            "danger_pool".to_string(),
+            SoundPool::new(vec![Sound::new("look-out")], SelectionMode::Sequential),
+        );
+        SoundPack { id: "p".into(), path: PathBuf::new(), pools }
+    }
+
+    #[test]
+    fn matches_the_bound_pool_and_selects_its_sound() {
+        let engine = engine_with_ping_rule();
+        let mut pack = pack_with_danger_pool();
+        let context_json = r#"{"event": {"Ping": {"kind": "Danger", "from_local_player": true}}}"#;
+
+        let result = simulate_selection(&engine, &mut pack, context_json).unwrap();
+
+        assert_eq!(result.matched_pool, Some("danger_pool".to_string()));
+        assert_eq!(result.selected_sound_id, Some("look-out".to_string()));
+    }
+
+    #[test]
+    fn an_event_with_no_bound_rule_selects_nothing() {
+        let engine = RulesEngine::new();
+        let mut pack = pack_with_danger_pool();
+        let context_json = r#"{"event": {"Ping": {"kind": "Danger", "from_local_player": true}}}"#;
+
+        let result = simulate_selection(&engine, &mut pack, context_json).unwrap();
+
+        assert_eq!(result.matched_pool, None);
+        assert_eq!(result.selected_sound_id, None);
+    }
+
+    #[test]
+    fn invalid_json_is_reported_as_an_error() {
+        let engine = RulesEngine::new();
+        let mut pack = pack_with_danger_pool();
+        assert!(matches!(simulate_selection(&engine, &mut pack, "not json"), Err(SimulationError::InvalidJson(_))));
+    }
+}
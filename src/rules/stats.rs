@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Tracks how many times each rule has fired this session, so users can
+/// see which rules actually trigger and which are dead weight. Derives
+/// `Serialize`/`Deserialize` so a caller can optionally persist it across
+/// sessions instead of starting from zero every launch, the same way
+/// [`crate::sound::pool::PoolCursor`] persists pool selection state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleStats {
+    fire_counts: HashMap<String, u32>,
+}
+
+impl RuleStats {
+    pub fn new() -> Self {
+        RuleStats::default()
+    }
+
+    /// Record that `rule_name` fired once.
+    pub fn record_fire(&mut self, rule_name: &str) {
+        *self.fire_counts.entry(rule_name.to_string()).or_insert(0) += 1;
+    }
+
+    /// How many times `rule_name` has fired so far, or 0 if never.
+    pub fn fire_count(&self, rule_name: &str) -> u32 {
+        self.fire_counts.get(rule_name).copied().unwrap_or(0)
+    }
+
+    /// Per-rule fire counts, for display or export.
+    pub fn get_rule_stats(&self) -> &HashMap<String, u32> {
+        &self.fire_counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn firing_a_rule_increments_its_counter() {
+        let mut stats = RuleStats::new();
+        stats.record_fire("danger");
+        stats.record_fire("danger");
+        stats.record_fire("baron_taken");
+
+        assert_eq!(stats.fire_count("danger"), 2);
+        assert_eq!(stats.fire_count("baron_taken"), 1);
+        assert_eq!(stats.fire_count("never_fired"), 0);
+    }
+
+    #[test]
+    fn get_rule_stats_exposes_every_tracked_rule() {
+        let mut stats = RuleStats::new();
+        stats.record_fire("danger");
+
+        assert_eq!(stats.get_rule_stats(), &HashMap::from([("danger".to_string(), 1)]));
+    }
+
+    #[test]
+    fn stats_round_trip_through_json_for_persistence() {
+        let mut stats = RuleStats::new();
+        stats.record_fire("danger");
+
+        let json = serde_json::to_string(&stats).unwrap();
+        let restored: RuleStats = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, stats);
+    }
+}
@@ -0,0 +1,74 @@
+use super::player_match::{PlayerNameMatcher, PlayerPatternKind};
+use super::RulesPack;
+
+/// Compile every rule's `player_pattern` in `pack`, catching typos at edit
+/// time rather than having a rule silently never match at runtime. Returns
+/// one human-readable error per invalid pattern, prefixed with the
+/// offending rule's name.
+pub fn validate_rule_patterns(pack: &RulesPack) -> Result<(), Vec<String>> {
+    let errors: Vec<String> = pack
+        .rules
+        .iter()
+        .filter_map(|rule| {
+            let kind = rule.player_pattern_kind?;
+            let pattern = rule.player_pattern.as_deref()?;
+            let result = match kind {
+                PlayerPatternKind::Wildcard => PlayerNameMatcher::wildcard(pattern),
+                PlayerPatternKind::Regex => PlayerNameMatcher::regex(pattern),
+            };
+            result.err().map(|err| format!("rule {:?}: {err}", rule.name))
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Rule;
+
+    fn pack_with(rules: Vec<Rule>) -> RulesPack {
+        RulesPack { name: "memes".into(), enabled: true, rules }
+    }
+
+    #[test]
+    fn valid_patterns_pass_validation() {
+        let pack = pack_with(vec![Rule {
+            name: "faker-only".into(),
+            event_kind: "ping".into(),
+            pool: "danger_pool".into(),
+            player_pattern_kind: Some(PlayerPatternKind::Regex),
+            player_pattern: Some(r"^Faker\d*$".into()),
+            ..Default::default()
+        }]);
+
+        assert!(validate_rule_patterns(&pack).is_ok());
+    }
+
+    #[test]
+    fn invalid_regex_is_reported_with_the_rule_name() {
+        let pack = pack_with(vec![Rule {
+            name: "broken".into(),
+            event_kind: "ping".into(),
+            pool: "danger_pool".into(),
+            player_pattern_kind: Some(PlayerPatternKind::Regex),
+            player_pattern: Some("(".into()),
+            ..Default::default()
+        }]);
+
+        let errors = validate_rule_patterns(&pack).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("broken"));
+    }
+
+    #[test]
+    fn rules_without_a_player_pattern_are_skipped() {
+        let pack = pack_with(vec![Rule { name: "no-pattern".into(), event_kind: "ping".into(), pool: "p".into(), ..Default::default() }]);
+        assert!(validate_rule_patterns(&pack).is_ok());
+    }
+}
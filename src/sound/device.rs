@@ -0,0 +1,57 @@
+/// Tracks the name of the default audio output device across polls so the
+/// local playback backend can detect a route change (e.g. headphones
+/// unplugged) and reopen its output stream instead of silently playing
+/// into a device that's gone. Pure decision logic — actually querying the
+/// current device and reopening the stream is left to the audio backend.
+#[derive(Debug, Clone, Default)]
+pub struct DeviceRouteMonitor {
+    last_known_device: Option<String>,
+}
+
+impl DeviceRouteMonitor {
+    pub fn new() -> Self {
+        DeviceRouteMonitor::default()
+    }
+
+    /// Whether the output stream should be reopened, given `current_device`
+    /// — the freshly-queried default device's name, or `None` if no device
+    /// is currently available. Updates the tracked device either way, so
+    /// the next call compares against this one.
+    pub fn should_reopen(&mut self, current_device: Option<&str>) -> bool {
+        let changed = self.last_known_device.as_deref() != current_device;
+        self.last_known_device = current_device.map(str::to_string);
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_first_detected_device_triggers_a_reopen() {
+        let mut monitor = DeviceRouteMonitor::new();
+        assert!(monitor.should_reopen(Some("Speakers")));
+    }
+
+    #[test]
+    fn an_unchanged_device_does_not_trigger_a_reopen() {
+        let mut monitor = DeviceRouteMonitor::new();
+        monitor.should_reopen(Some("Speakers"));
+        assert!(!monitor.should_reopen(Some("Speakers")));
+    }
+
+    #[test]
+    fn a_changed_device_name_triggers_a_reopen() {
+        let mut monitor = DeviceRouteMonitor::new();
+        monitor.should_reopen(Some("Speakers"));
+        assert!(monitor.should_reopen(Some("Headphones")));
+    }
+
+    #[test]
+    fn the_device_disappearing_triggers_a_reopen() {
+        let mut monitor = DeviceRouteMonitor::new();
+        monitor.should_reopen(Some("Headphones"));
+        assert!(monitor.should_reopen(None));
+    }
+}
@@ -0,0 +1,59 @@
+/// Detects whether the OS's "Do Not Disturb" (Windows calls it Focus
+/// Assist) mode is currently active. Behind a trait so platforms without a
+/// detection API can plug in [`NoopDndDetector`] instead of every caller
+/// special-casing "unsupported platform".
+pub trait DoNotDisturbDetector {
+    fn is_dnd_active(&self) -> bool;
+}
+
+/// Always reports DND as inactive — the fallback for platforms (or build
+/// targets) with no detection API available.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopDndDetector;
+
+impl DoNotDisturbDetector for NoopDndDetector {
+    fn is_dnd_active(&self) -> bool {
+        false
+    }
+}
+
+/// Whether playback should be muted right now, given the detector's
+/// current reading and whether auto-mute-during-DND is turned on in
+/// config (see [`crate::config::ResolvedConfig::auto_mute_during_dnd`]).
+pub fn should_mute_for_dnd(detector: &impl DoNotDisturbDetector, auto_mute_during_dnd: bool) -> bool {
+    auto_mute_during_dnd && detector.is_dnd_active()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockDndDetector {
+        active: bool,
+    }
+
+    impl DoNotDisturbDetector for MockDndDetector {
+        fn is_dnd_active(&self) -> bool {
+            self.active
+        }
+    }
+
+    #[test]
+    fn noop_detector_never_reports_dnd_active() {
+        assert!(!NoopDndDetector.is_dnd_active());
+    }
+
+    #[test]
+    fn auto_mute_disabled_never_mutes_even_during_dnd() {
+        let detector = MockDndDetector { active: true };
+        assert!(!should_mute_for_dnd(&detector, false));
+    }
+
+    #[test]
+    fn auto_mute_enabled_mutes_only_while_dnd_is_active() {
+        let active = MockDndDetector { active: true };
+        let inactive = MockDndDetector { active: false };
+        assert!(should_mute_for_dnd(&active, true));
+        assert!(!should_mute_for_dnd(&inactive, true));
+    }
+}
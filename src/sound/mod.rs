@@ -0,0 +1,45 @@
+pub mod device;
+pub mod dnd;
+pub mod pack;
+pub mod playback;
+pub mod pool;
+pub mod preset;
+pub mod reachability;
+pub mod tone;
+pub mod volume;
+
+pub use device::DeviceRouteMonitor;
+pub use dnd::{should_mute_for_dnd, DoNotDisturbDetector, NoopDndDetector};
+pub use pack::SoundPack;
+pub use playback::{
+    play_cue_after_delay, play_manual_sound, play_sound_for_event, ManualSoundSource, PlaybackDelay, PlaybackQueue,
+    QueuedClip,
+};
+pub use pool::{SelectionMode, SoundPool};
+pub use preset::{apply_preset_pack, ApplyPresetError, PresetPack};
+pub use reachability::check_pack_reachability;
+pub use tone::{ensure_base_pack_assets, generate_tone, ToneSpec};
+pub use volume::{BigPlayVolumeBoost, DeadPlayerVolume, FirstBloodVolumeBoost};
+
+/// A single playable sound entry in a pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sound {
+    pub id: String,
+    pub enabled: bool,
+    pub weight: u32,
+    /// Where this sound's audio came from, if it was downloaded rather
+    /// than bundled (e.g. a YouTube clip) — needed to re-fetch or bulk
+    /// export the pack's referenced URLs.
+    pub source_url: Option<String>,
+}
+
+impl Sound {
+    pub fn new(id: impl Into<String>) -> Self {
+        Sound {
+            id: id.into(),
+            enabled: true,
+            weight: 1,
+            source_url: None,
+        }
+    }
+}
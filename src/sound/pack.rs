@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::pool::SelectionMode;
+use super::Sound;
+
+/// On-disk representation of `sound-pack.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundPackFile {
+    pub id: String,
+    pub pools: HashMap<String, PoolFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolFile {
+    pub mode: SelectionModeFile,
+    pub sounds: Vec<SoundFile>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionModeFile {
+    Random,
+    Sequential,
+    ShuffleBag,
+}
+
+impl From<SelectionModeFile> for SelectionMode {
+    fn from(value: SelectionModeFile) -> Self {
+        match value {
+            SelectionModeFile::Random => SelectionMode::Random,
+            SelectionModeFile::Sequential => SelectionMode::Sequential,
+            SelectionModeFile::ShuffleBag => SelectionMode::ShuffleBag,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoundFile {
+    pub id: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+    #[serde(default, rename = "url")]
+    pub source_url: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ValidationError {
+    #[error("not valid JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+    #[error("pack has no pools")]
+    NoPools,
+    #[error("pool {0:?} has no sounds")]
+    EmptyPool(String),
+}
+
+/// Validate a pasted sound-pack JSON string (e.g. from a "share my pack"
+/// snippet) and return it re-serialized with defaults (like `enabled`,
+/// `weight`) filled in explicitly.
+pub fn validate_and_normalize(raw: &str) -> Result<String, ValidationError> {
+    let file: SoundPackFile = serde_json::from_str(raw)?;
+    if file.pools.is_empty() {
+        return Err(ValidationError::NoPools);
+    }
+    for (name, pool) in &file.pools {
+        if pool.sounds.is_empty() {
+            return Err(ValidationError::EmptyPool(name.clone()));
+        }
+    }
+    Ok(serde_json::to_string_pretty(&file).expect("serializing a parsed pack cannot fail"))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PackError {
+    #[error("failed to read sound pack at {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse sound pack at {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// A loaded sound pack: its source path (for reloads) plus one [`super::SoundPool`]
+/// per named pool (e.g. "kill", "death", "objective_spawn").
+pub struct SoundPack {
+    pub id: String,
+    pub path: PathBuf,
+    pub pools: HashMap<String, super::SoundPool>,
+}
+
+impl SoundPack {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PackError> {
+        let path = path.as_ref().to_path_buf();
+        let raw = std::fs::read_to_string(&path).map_err(|source| PackError::Read {
+            path: path.clone(),
+            source,
+        })?;
+        let file: SoundPackFile =
+            serde_json::from_str(&raw).map_err(|source| PackError::Parse {
+                path: path.clone(),
+                source,
+            })?;
+
+        let pools = file
+            .pools
+            .into_iter()
+            .map(|(name, pool)| {
+                let sounds = pool
+                    .sounds
+                    .into_iter()
+                    .map(|s| Sound {
+                        id: s.id,
+                        enabled: s.enabled,
+                        weight: s.weight,
+                        source_url: s.source_url,
+                    })
+                    .collect();
+                (name, super::SoundPool::new(sounds, pool.mode.into()))
+            })
+            .collect();
+
+        Ok(SoundPack {
+            id: file.id,
+            path,
+            pools,
+        })
+    }
+
+    /// Clear the sequential cursor and shuffle bag on every pool, so users
+    /// testing variety can restart the rotation from the beginning without
+    /// reloading the whole pack.
+    pub fn reset_selection_state(&mut self) {
+        for pool in self.pools.values_mut() {
+            pool.reset_cursor();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(matches!(validate_and_normalize("not json"), Err(ValidationError::InvalidJson(_))));
+    }
+
+    #[test]
+    fn rejects_a_pack_with_no_pools() {
+        let raw = r#"{"id": "empty", "pools": {}}"#;
+        assert!(matches!(validate_and_normalize(raw), Err(ValidationError::NoPools)));
+    }
+
+    #[test]
+    fn rejects_a_pool_with_no_sounds() {
+        let raw = r#"{"id": "p", "pools": {"kill": {"mode": "random", "sounds": []}}}"#;
+        assert!(matches!(validate_and_normalize(raw), Err(ValidationError::EmptyPool(_))));
+    }
+
+    #[test]
+    fn normalizes_a_valid_pack_filling_in_defaults() {
+        let raw = r#"{"id": "p", "pools": {"kill": {"mode": "random", "sounds": [{"id": "a"}]}}}"#;
+        let normalized = validate_and_normalize(raw).unwrap();
+        assert!(normalized.contains("\"enabled\": true"));
+        assert!(normalized.contains("\"weight\": 1"));
+    }
+
+    #[test]
+    fn reset_selection_state_restarts_every_pool_from_the_beginning() {
+        let sounds = vec![Sound::new("a"), Sound::new("b")];
+        let mut pools = HashMap::new();
+        pools.insert("kill".to_string(), super::super::SoundPool::new(sounds, SelectionMode::Sequential));
+        let mut pack = SoundPack { id: "p".into(), path: PathBuf::new(), pools };
+
+        let pool = pack.pools.get_mut("kill").unwrap();
+        assert_eq!(pool.select_next().unwrap().id, "a");
+        assert_eq!(pool.select_next().unwrap().id, "b");
+
+        pack.reset_selection_state();
+
+        assert_eq!(pack.pools.get_mut("kill").unwrap().select_next().unwrap().id, "a");
+    }
+}
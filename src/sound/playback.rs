@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+/// A clip waiting to be played, with enough timing info to schedule a
+/// crossfade into whatever plays next, plus how notable it is relative to
+/// other queued clips (see [`PlaybackQueue::enqueue_with_priority`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueuedClip {
+    pub id: String,
+    pub duration: Duration,
+    pub priority: u8,
+}
+
+impl QueuedClip {
+    /// A clip for a kill callout, its priority set from the multikill
+    /// context's [`crate::game::KillContext::kill_count`] so a pentakill
+    /// always outranks a plain single kill already in the queue.
+    pub fn for_kill(id: impl Into<String>, duration: Duration, kill_context: crate::game::KillContext) -> Self {
+        QueuedClip { id: id.into(), duration, priority: kill_context.kill_count() as u8 }
+    }
+}
+
+/// Queue of upcoming clips that overlaps consecutive clips by
+/// `crossfade` instead of waiting for each to fully finish.
+pub struct PlaybackQueue {
+    crossfade: Duration,
+    queue: Vec<QueuedClip>,
+}
+
+impl PlaybackQueue {
+    pub fn new(crossfade: Duration) -> Self {
+        PlaybackQueue {
+            crossfade,
+            queue: Vec::new(),
+        }
+    }
+
+    pub fn enqueue(&mut self, clip: QueuedClip) {
+        self.queue.push(clip);
+    }
+
+    /// Enqueue `clip`, first dropping any currently-queued clip less
+    /// notable than it — so a pentakill doesn't wait behind three queued
+    /// single-kill cues, it jumps straight to the front instead.
+    pub fn enqueue_with_priority(&mut self, clip: QueuedClip) {
+        self.queue.retain(|queued| queued.priority >= clip.priority);
+        self.queue.push(clip);
+    }
+
+    /// Compute when each queued clip should start, overlapping the tail of
+    /// the previous clip by `crossfade` (but never starting before time 0,
+    /// and never overlapping past the previous clip's start).
+    pub fn schedule(&self) -> Vec<Duration> {
+        let mut starts = Vec::with_capacity(self.queue.len());
+        let mut cursor = Duration::ZERO;
+        let mut prev_start = Duration::ZERO;
+        for (i, clip) in self.queue.iter().enumerate() {
+            if i > 0 {
+                cursor = cursor.saturating_sub(self.crossfade).max(prev_start);
+            }
+            starts.push(cursor);
+            prev_start = cursor;
+            cursor += clip.duration;
+        }
+        starts
+    }
+}
+
+/// A sound to play on demand (e.g. a hotkey-triggered taunt), identified by
+/// the pool id the frontend resolved it to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManualSoundSource {
+    pub pool_id: String,
+}
+
+/// Play `source` immediately through `sink` (the active backend's play
+/// call), bypassing rule matching and any cooldowns entirely — the user's
+/// hotkey press is itself the trigger, so there's nothing to gate on.
+pub fn play_manual_sound(source: &ManualSoundSource, sink: impl FnOnce(&str)) {
+    sink(&source.pool_id);
+}
+
+/// A fixed delay applied uniformly before every cue plays, so users can
+/// nudge callouts to align with the game audio they actually hear despite
+/// Live Client polling latency. There's no way to play a cue *earlier*, so
+/// a negative request clamps to 0 rather than erroring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlaybackDelay {
+    delay_ms: u32,
+}
+
+impl PlaybackDelay {
+    pub fn from_millis(requested_ms: i64) -> Self {
+        PlaybackDelay { delay_ms: requested_ms.max(0) as u32 }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(u64::from(self.delay_ms))
+    }
+}
+
+/// Play a cue through `play`, first waiting out `delay` via `sleep` so the
+/// delay is applied uniformly no matter which playback path triggered it.
+pub fn play_cue_after_delay(delay: PlaybackDelay, sleep: impl FnOnce(Duration), play: impl FnOnce()) {
+    sleep(delay.duration());
+    play();
+}
+
+/// Forward an event via `forward` unconditionally, then play it via `play`
+/// unless `paused` (see [`crate::game::GameState::playback_paused`]) — so
+/// event-only mode keeps whatever forwarding downstream consumers rely on
+/// (e.g. stats) while silencing sound. Shared by both the local and
+/// Discord playback paths so pausing is consistent across them.
+pub fn play_sound_for_event(paused: bool, forward: impl FnOnce(), play: impl FnOnce()) {
+    forward();
+    if !paused {
+        play();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clip(id: &str, secs: u64) -> QueuedClip {
+        QueuedClip { id: id.into(), duration: Duration::from_secs(secs), priority: 0 }
+    }
+
+    #[test]
+    fn clips_overlap_by_the_crossfade_duration() {
+        let mut queue = PlaybackQueue::new(Duration::from_millis(500));
+        queue.enqueue(clip("a", 2));
+        queue.enqueue(clip("b", 3));
+        queue.enqueue(clip("c", 1));
+
+        let starts = queue.schedule();
+        assert_eq!(starts[0], Duration::ZERO);
+        assert_eq!(starts[1], Duration::from_millis(1500));
+        assert_eq!(starts[2], Duration::from_millis(4000));
+    }
+
+    #[test]
+    fn a_crossfade_longer_than_a_clip_does_not_go_negative() {
+        let mut queue = PlaybackQueue::new(Duration::from_secs(5));
+        queue.enqueue(clip("a", 1));
+        queue.enqueue(clip("b", 1));
+
+        let starts = queue.schedule();
+        assert_eq!(starts[1], Duration::ZERO);
+    }
+
+    #[test]
+    fn enqueuing_a_penta_clears_pending_kill_cues() {
+        use crate::game::KillContext;
+
+        let mut queue = PlaybackQueue::new(Duration::ZERO);
+        queue.enqueue_with_priority(QueuedClip::for_kill("kill1", Duration::from_secs(1), KillContext::Single));
+        queue.enqueue_with_priority(QueuedClip::for_kill("kill2", Duration::from_secs(1), KillContext::Single));
+        queue.enqueue_with_priority(QueuedClip::for_kill("kill3", Duration::from_secs(1), KillContext::Single));
+
+        queue.enqueue_with_priority(QueuedClip::for_kill("penta", Duration::from_secs(3), KillContext::Penta));
+
+        assert_eq!(queue.queue.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), vec!["penta"]);
+    }
+
+    #[test]
+    fn enqueue_with_priority_does_not_clear_clips_at_least_as_notable() {
+        let mut queue = PlaybackQueue::new(Duration::ZERO);
+        queue.enqueue_with_priority(clip_with_priority("a", 1, 3));
+        queue.enqueue_with_priority(clip_with_priority("b", 1, 3));
+
+        assert_eq!(queue.queue.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    fn clip_with_priority(id: &str, secs: u64, priority: u8) -> QueuedClip {
+        QueuedClip { id: id.into(), duration: Duration::from_secs(secs), priority }
+    }
+
+    #[test]
+    fn manual_playback_invokes_the_sink_with_the_source_pool() {
+        use std::cell::RefCell;
+
+        let source = ManualSoundSource { pool_id: "taunt".into() };
+        let played = RefCell::new(None);
+        play_manual_sound(&source, |pool_id| *played.borrow_mut() = Some(pool_id.to_string()));
+
+        assert_eq!(played.into_inner(), Some("taunt".to_string()));
+    }
+
+    #[test]
+    fn negative_delay_requests_clamp_to_zero() {
+        assert_eq!(PlaybackDelay::from_millis(-50).duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn paused_playback_still_forwards_but_skips_the_sound() {
+        use std::cell::Cell;
+
+        let forwarded = Cell::new(false);
+        let played = Cell::new(false);
+
+        play_sound_for_event(true, || forwarded.set(true), || played.set(true));
+
+        assert!(forwarded.get());
+        assert!(!played.get());
+    }
+
+    #[test]
+    fn unpaused_playback_forwards_and_plays() {
+        use std::cell::Cell;
+
+        let forwarded = Cell::new(false);
+        let played = Cell::new(false);
+
+        play_sound_for_event(false, || forwarded.set(true), || played.set(true));
+
+        assert!(forwarded.get());
+        assert!(played.get());
+    }
+
+    #[test]
+    fn the_configured_delay_is_applied_before_the_play_call() {
+        use std::cell::RefCell;
+
+        let order = RefCell::new(Vec::new());
+        let delay = PlaybackDelay::from_millis(200);
+
+        play_cue_after_delay(
+            delay,
+            |d| order.borrow_mut().push(format!("sleep({}ms)", d.as_millis())),
+            || order.borrow_mut().push("play".to_string()),
+        );
+
+        assert_eq!(order.into_inner(), vec!["sleep(200ms)".to_string(), "play".to_string()]);
+    }
+}
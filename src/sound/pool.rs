@@ -0,0 +1,300 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::Sound;
+
+/// The part of a [`SoundPool`]'s state that needs to survive between games
+/// so Sequential/ShuffleBag don't restart from scratch on every launch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolCursor {
+    sequential_cursor: usize,
+    shuffle_bag: Vec<usize>,
+}
+
+/// How a [`SoundPool`] picks the next sound to play for a given cue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Weighted random pick, independent of prior picks.
+    Random,
+    /// Walk the enabled sounds in order, wrapping around.
+    Sequential,
+    /// Shuffle the enabled sounds into a bag and dispense one at a time,
+    /// guaranteeing every sound is heard before any repeat.
+    ShuffleBag,
+}
+
+/// A pool of interchangeable sounds for a single cue, plus whatever
+/// per-mode state is needed to pick the next one.
+pub struct SoundPool {
+    sounds: Vec<Sound>,
+    mode: SelectionMode,
+    sequential_cursor: usize,
+    shuffle_bag: Vec<usize>,
+}
+
+impl SoundPool {
+    pub fn new(sounds: Vec<Sound>, mode: SelectionMode) -> Self {
+        SoundPool {
+            sounds,
+            mode,
+            sequential_cursor: 0,
+            shuffle_bag: Vec::new(),
+        }
+    }
+
+    pub fn sounds(&self) -> &[Sound] {
+        &self.sounds
+    }
+
+    /// Snapshot the per-mode cursor state so it can be persisted across games.
+    pub fn export_cursor(&self) -> PoolCursor {
+        PoolCursor {
+            sequential_cursor: self.sequential_cursor,
+            shuffle_bag: self.shuffle_bag.clone(),
+        }
+    }
+
+    /// Restore cursor state previously captured with [`Self::export_cursor`].
+    pub fn restore_cursor(&mut self, cursor: PoolCursor) {
+        self.sequential_cursor = cursor.sequential_cursor;
+        self.shuffle_bag = cursor.shuffle_bag;
+    }
+
+    /// Clear the sequential cursor and shuffle bag so the next `select_next()`
+    /// starts from the beginning, as if the pool had just been loaded.
+    pub fn reset_cursor(&mut self) {
+        self.sequential_cursor = 0;
+        self.shuffle_bag.clear();
+    }
+
+    /// Run `select_next()` `trials` times and tally how often each sound id was
+    /// picked, so users previewing a pool can sanity-check its weighting or
+    /// rotation without actually connecting to voice.
+    pub fn preview(&mut self, trials: u32) -> std::collections::HashMap<String, u32> {
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..trials {
+            if let Some(sound) = self.select_next() {
+                *counts.entry(sound.id.clone()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Each enabled sound's effective selection probability given its
+    /// weight, using the same "zero/missing weight counts as 1" rule as
+    /// [`Self::next_random`] — so the editor's preview matches what
+    /// actually gets picked.
+    pub fn compute_pool_probabilities(&self) -> Vec<(String, f32)> {
+        let enabled = self.enabled_indices();
+        let total_weight: u32 = enabled.iter().map(|&i| self.sounds[i].weight.max(1)).sum();
+        if total_weight == 0 {
+            return Vec::new();
+        }
+        enabled
+            .iter()
+            .map(|&i| {
+                let weight = self.sounds[i].weight.max(1);
+                (self.sounds[i].id.clone(), weight as f32 / total_weight as f32)
+            })
+            .collect()
+    }
+
+    fn enabled_indices(&self) -> Vec<usize> {
+        self.sounds
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.enabled)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Pick the next sound according to `self.mode`, or `None` if no sounds
+    /// are enabled.
+    pub fn select_next(&mut self) -> Option<&Sound> {
+        match self.mode {
+            SelectionMode::Random => self.next_random(),
+            SelectionMode::Sequential => self.next_sequential(),
+            SelectionMode::ShuffleBag => self.next_shuffle_bag(),
+        }
+    }
+
+    fn next_random(&self) -> Option<&Sound> {
+        let enabled = self.enabled_indices();
+        if enabled.is_empty() {
+            return None;
+        }
+        let total_weight: u32 = enabled.iter().map(|&i| self.sounds[i].weight.max(1)).sum();
+        let mut pick = rand::thread_rng().gen_range(0..total_weight);
+        for &i in &enabled {
+            let weight = self.sounds[i].weight.max(1);
+            if pick < weight {
+                return Some(&self.sounds[i]);
+            }
+            pick -= weight;
+        }
+        enabled.last().map(|&i| &self.sounds[i])
+    }
+
+    fn next_sequential(&mut self) -> Option<&Sound> {
+        let enabled = self.enabled_indices();
+        if enabled.is_empty() {
+            return None;
+        }
+        let pos = self.sequential_cursor % enabled.len();
+        self.sequential_cursor = self.sequential_cursor.wrapping_add(1);
+        Some(&self.sounds[enabled[pos]])
+    }
+
+    fn next_shuffle_bag(&mut self) -> Option<&Sound> {
+        let enabled = self.enabled_indices();
+        if enabled.is_empty() {
+            return None;
+        }
+        if self.shuffle_bag.is_empty() {
+            self.shuffle_bag = enabled;
+            self.shuffle_bag.shuffle(&mut rand::thread_rng());
+        }
+        let idx = self.shuffle_bag.pop()?;
+        Some(&self.sounds[idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_of(ids: &[&str]) -> SoundPool {
+        let sounds = ids.iter().map(|id| Sound::new(*id)).collect();
+        SoundPool::new(sounds, SelectionMode::ShuffleBag)
+    }
+
+    #[test]
+    fn shuffle_bag_covers_every_enabled_sound_exactly_once_per_cycle() {
+        let mut pool = pool_of(&["a", "b", "c", "d"]);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..4 {
+            let sound = pool.select_next().expect("pool should not be empty");
+            assert!(seen.insert(sound.id.clone()), "sound repeated before bag exhausted");
+        }
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[test]
+    fn shuffle_bag_skips_disabled_sounds() {
+        let mut pool = pool_of(&["a", "b", "c"]);
+        pool.sounds[1].enabled = false;
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..2 {
+            seen.insert(pool.select_next().unwrap().id.clone());
+        }
+        assert!(!seen.contains("b"));
+        assert_eq!(seen.len(), 2);
+    }
+
+    #[test]
+    fn cursor_survives_an_export_restore_round_trip() {
+        let mut pool = pool_of(&["a", "b", "c"]);
+        let first = pool.select_next().unwrap().id.clone();
+        let cursor = pool.export_cursor();
+
+        let mut restored = pool_of(&["a", "b", "c"]);
+        restored.restore_cursor(cursor);
+        let second = restored.select_next().unwrap().id.clone();
+
+        assert_ne!(first, second, "restored bag should not re-deal the already-dispensed sound");
+    }
+
+    #[test]
+    fn preview_over_a_full_shuffle_bag_cycle_counts_each_sound_once() {
+        let mut pool = pool_of(&["a", "b", "c"]);
+        let counts = pool.preview(3);
+        assert_eq!(counts.len(), 3);
+        assert!(counts.values().all(|&c| c == 1));
+    }
+
+    #[test]
+    fn reset_cursor_restarts_a_sequential_pool_from_the_first_sound() {
+        let mut pool = SoundPool::new(
+            vec![Sound::new("a"), Sound::new("b")],
+            SelectionMode::Sequential,
+        );
+        assert_eq!(pool.select_next().unwrap().id, "a");
+        assert_eq!(pool.select_next().unwrap().id, "b");
+
+        pool.reset_cursor();
+
+        assert_eq!(pool.select_next().unwrap().id, "a");
+    }
+
+    #[test]
+    fn equal_weights_split_probability_evenly() {
+        let pool = pool_of(&["a", "b"]);
+        let probabilities = pool.compute_pool_probabilities();
+        assert_eq!(probabilities, vec![("a".to_string(), 0.5), ("b".to_string(), 0.5)]);
+    }
+
+    #[test]
+    fn probabilities_scale_with_relative_weight_and_sum_to_one() {
+        let mut pool = pool_of(&["a", "b", "c"]);
+        pool.sounds[0].weight = 2;
+        pool.sounds[1].weight = 1;
+        pool.sounds[2].weight = 1;
+
+        let probabilities = pool.compute_pool_probabilities();
+
+        assert_eq!(probabilities, vec![
+            ("a".to_string(), 0.5),
+            ("b".to_string(), 0.25),
+            ("c".to_string(), 0.25),
+        ]);
+        let total: f32 = probabilities.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn a_zero_weight_is_treated_as_the_minimum_weight_of_one() {
+        let mut pool = pool_of(&["a", "b"]);
+        pool.sounds[0].weight = 0;
+
+        let probabilities = pool.compute_pool_probabilities();
+
+        assert_eq!(probabilities, vec![("a".to_string(), 0.5), ("b".to_string(), 0.5)]);
+    }
+
+    #[test]
+    fn disabled_sounds_are_excluded_from_probabilities() {
+        let mut pool = pool_of(&["a", "b"]);
+        pool.sounds[1].enabled = false;
+
+        assert_eq!(pool.compute_pool_probabilities(), vec![("a".to_string(), 1.0)]);
+    }
+
+    #[test]
+    fn sequential_wraps_around_enabled_sounds() {
+        let mut pool = SoundPool::new(
+            vec![Sound::new("a"), Sound::new("b")],
+            SelectionMode::Sequential,
+        );
+        let order: Vec<_> = (0..4).map(|_| pool.select_next().unwrap().id.clone()).collect();
+        assert_eq!(order, vec!["a", "b", "a", "b"]);
+    }
+
+    #[test]
+    fn disabling_a_sound_mid_sequence_does_not_panic_on_an_out_of_range_cursor() {
+        let mut pool = SoundPool::new(
+            vec![Sound::new("a"), Sound::new("b"), Sound::new("c")],
+            SelectionMode::Sequential,
+        );
+        assert_eq!(pool.select_next().unwrap().id, "a");
+        assert_eq!(pool.select_next().unwrap().id, "b");
+
+        pool.sounds[2].enabled = false;
+
+        // The raw cursor is now 2, which is out of range for the shrunk
+        // enabled set; `next_sequential` re-wraps it via modulo rather than
+        // indexing directly, so this just keeps cycling instead of panicking.
+        assert_eq!(pool.select_next().unwrap().id, "a");
+        assert_eq!(pool.select_next().unwrap().id, "b");
+    }
+}
@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use super::pack::PackError;
+use super::SoundPack;
+
+/// Curated packs shipped with the app so new users get a working setup
+/// without building one from scratch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresetPack {
+    Hype,
+    Minimal,
+}
+
+impl PresetPack {
+    /// The directory name this preset is bundled and installed under.
+    pub fn id(&self) -> &'static str {
+        match self {
+            PresetPack::Hype => "hype",
+            PresetPack::Minimal => "minimal",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "hype" => Some(PresetPack::Hype),
+            "minimal" => Some(PresetPack::Minimal),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApplyPresetError {
+    #[error("no preset pack named {0:?}")]
+    UnknownPreset(String),
+    #[error("failed to copy preset pack: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to load the installed preset pack: {0}")]
+    Pack(#[from] PackError),
+}
+
+/// Copy the bundled preset pack `name` from `resource_dir/packs/<name>`
+/// into `app_data_dir/packs/<name>`, then load it so the caller can
+/// activate it (e.g. via `ConfigOverrides::active_sound_pack`) using the
+/// returned pack's id.
+pub fn apply_preset_pack(resource_dir: &Path, app_data_dir: &Path, name: &str) -> Result<SoundPack, ApplyPresetError> {
+    let preset = PresetPack::from_id(name).ok_or_else(|| ApplyPresetError::UnknownPreset(name.to_string()))?;
+    let source_dir = resource_dir.join("packs").join(preset.id());
+    let dest_dir = app_data_dir.join("packs").join(preset.id());
+    copy_dir_recursive(&source_dir, &dest_dir)?;
+    Ok(SoundPack::load(dest_dir.join("sound-pack.json"))?)
+}
+
+fn copy_dir_recursive(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&entry.path(), &dest)?;
+        } else {
+            std::fs::copy(entry.path(), dest)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_resource_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("scout-for-lol-test-preset-resources-{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        let pack_dir = dir.join("packs").join(name);
+        std::fs::create_dir_all(&pack_dir).unwrap();
+        std::fs::write(
+            pack_dir.join("sound-pack.json"),
+            r#"{"id": "hype", "pools": {"kill": {"mode": "random", "sounds": [{"id": "a"}]}}}"#,
+        )
+        .unwrap();
+        std::fs::write(pack_dir.join("a.mp3"), [0u8; 4]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn applying_a_preset_installs_and_loads_it() {
+        let resource_dir = fake_resource_dir("hype");
+        let app_data_dir = std::env::temp_dir().join("scout-for-lol-test-preset-app-data-hype");
+        let _ = std::fs::remove_dir_all(&app_data_dir);
+
+        let pack = apply_preset_pack(&resource_dir, &app_data_dir, "hype").unwrap();
+
+        assert_eq!(pack.id, "hype");
+        assert!(app_data_dir.join("packs/hype/sound-pack.json").exists());
+        assert!(app_data_dir.join("packs/hype/a.mp3").exists());
+
+        std::fs::remove_dir_all(&resource_dir).unwrap();
+        std::fs::remove_dir_all(&app_data_dir).unwrap();
+    }
+
+    #[test]
+    fn an_unknown_preset_name_is_rejected() {
+        let resource_dir = fake_resource_dir("hype-for-unknown-test");
+        let app_data_dir = std::env::temp_dir().join("scout-for-lol-test-preset-app-data-unknown");
+
+        assert!(matches!(
+            apply_preset_pack(&resource_dir, &app_data_dir, "does-not-exist"),
+            Err(ApplyPresetError::UnknownPreset(_))
+        ));
+
+        std::fs::remove_dir_all(&resource_dir).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+use super::SoundPack;
+use crate::youtube::DownloadCache;
+
+fn is_youtube_url(url: &str) -> bool {
+    url.contains("youtube.com") || url.contains("youtu.be")
+}
+
+/// Whether a bundled local sound with id `id` exists somewhere in `dir`,
+/// regardless of its file extension.
+fn local_file_exists(dir: &std::path::Path, id: &str) -> bool {
+    std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|entry| entry.path().file_stem().is_some_and(|stem| stem == id))
+}
+
+/// Preflight check: for every sound across `pack`'s pools, verify it's
+/// actually playable — a cached, non-broken download for YouTube URLs, a
+/// `head_check` for any other URL, or a bundled file next to the pack for
+/// plain (non-URL) sounds. Catches dead links before a game rather than
+/// mid-callout.
+pub fn check_pack_reachability(
+    pack: &SoundPack,
+    cache: &DownloadCache,
+    head_check: impl Fn(&str) -> bool,
+) -> Vec<(String, bool)> {
+    let dir = pack.path.parent();
+    pack.pools
+        .values()
+        .flat_map(|pool| pool.sounds())
+        .map(|sound| {
+            let reachable = match &sound.source_url {
+                Some(url) if is_youtube_url(url) => cache.get(url).is_some() && !cache.is_broken(url),
+                Some(url) => head_check(url),
+                None => dir.is_some_and(|dir| local_file_exists(dir, &sound.id)),
+            };
+            (sound.id.clone(), reachable)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sound::pool::SelectionMode;
+    use crate::sound::{Sound, SoundPool};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn pack_with(sounds: Vec<Sound>, dir: &std::path::Path) -> SoundPack {
+        let mut pools = HashMap::new();
+        pools.insert("kill".to_string(), SoundPool::new(sounds, SelectionMode::Random));
+        SoundPack { id: "p".into(), path: dir.join("sound-pack.json"), pools }
+    }
+
+    fn sound_with_url(id: &str, url: &str) -> Sound {
+        let mut sound = Sound::new(id);
+        sound.source_url = Some(url.to_string());
+        sound
+    }
+
+    #[test]
+    fn a_cached_non_broken_youtube_url_is_reachable() {
+        let dir = std::env::temp_dir();
+        let mut cache = DownloadCache::new();
+        let url = "https://youtu.be/abc";
+        cache.insert(url.to_string(), PathBuf::from("/tmp/abc.mp3"));
+
+        let pack = pack_with(vec![sound_with_url("a", url)], &dir);
+        let report = check_pack_reachability(&pack, &cache, |_| false);
+
+        assert_eq!(report, vec![("a".to_string(), true)]);
+    }
+
+    #[test]
+    fn an_uncached_youtube_url_is_not_reachable() {
+        let dir = std::env::temp_dir();
+        let cache = DownloadCache::new();
+        let pack = pack_with(vec![sound_with_url("a", "https://youtu.be/missing")], &dir);
+
+        let report = check_pack_reachability(&pack, &cache, |_| true);
+
+        assert_eq!(report, vec![("a".to_string(), false)]);
+    }
+
+    #[test]
+    fn a_present_bundled_file_is_reachable() {
+        let dir = std::env::temp_dir().join(format!("scout-reachability-test-present-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.mp3"), [0u8; 4]).unwrap();
+
+        let pack = pack_with(vec![Sound::new("a")], &dir);
+        let report = check_pack_reachability(&pack, &DownloadCache::new(), |_| false);
+
+        assert_eq!(report, vec![("a".to_string(), true)]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn a_missing_bundled_file_is_not_reachable() {
+        let dir = std::env::temp_dir().join(format!("scout-reachability-test-missing-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let pack = pack_with(vec![Sound::new("a")], &dir);
+        let report = check_pack_reachability(&pack, &DownloadCache::new(), |_| false);
+
+        assert_eq!(report, vec![("a".to_string(), false)]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn a_direct_url_uses_the_head_check() {
+        let dir = std::env::temp_dir();
+        let pack = pack_with(vec![sound_with_url("a", "https://example.com/beep.mp3")], &dir);
+
+        assert_eq!(check_pack_reachability(&pack, &DownloadCache::new(), |_| true), vec![("a".to_string(), true)]);
+        assert_eq!(check_pack_reachability(&pack, &DownloadCache::new(), |_| false), vec![("a".to_string(), false)]);
+    }
+}
@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// The WAV encoding to generate a base tone at. Lets constrained setups
+/// drop to a lower sample rate/bit depth, or fidelity-conscious users raise
+/// it, instead of the app hardcoding one format for everyone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToneSpec {
+    pub sample_rate_hz: u32,
+    pub bit_depth: u16,
+}
+
+impl Default for ToneSpec {
+    fn default() -> Self {
+        ToneSpec { sample_rate_hz: 44_100, bit_depth: 16 }
+    }
+}
+
+/// Render a single sine tone at `frequency_hz` for `duration`, encoded as a
+/// complete PCM WAV file (header included) per `spec`.
+pub fn generate_tone(frequency_hz: f32, duration: Duration, spec: ToneSpec) -> Vec<u8> {
+    let num_samples = (spec.sample_rate_hz as f32 * duration.as_secs_f32()) as u32;
+    let bytes_per_sample = (spec.bit_depth / 8) as u32;
+    let max_amplitude = (1i64 << (spec.bit_depth - 1)) - 1;
+
+    let mut data = Vec::with_capacity((num_samples * bytes_per_sample) as usize);
+    for n in 0..num_samples {
+        let t = n as f32 / spec.sample_rate_hz as f32;
+        let sample = (t * frequency_hz * std::f32::consts::TAU).sin();
+        let quantized = (sample * max_amplitude as f32) as i64;
+        data.extend_from_slice(&quantized.to_le_bytes()[..bytes_per_sample as usize]);
+    }
+
+    write_wav_header(&data, spec)
+}
+
+fn write_wav_header(data: &[u8], spec: ToneSpec) -> Vec<u8> {
+    let num_channels: u16 = 1;
+    let block_align = num_channels * spec.bit_depth / 8;
+    let byte_rate = spec.sample_rate_hz * block_align as u32;
+
+    let mut wav = Vec::with_capacity(44 + data.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&num_channels.to_le_bytes());
+    wav.extend_from_slice(&spec.sample_rate_hz.to_le_bytes());
+    wav.extend_from_slice(&byte_rate.to_le_bytes());
+    wav.extend_from_slice(&block_align.to_le_bytes());
+    wav.extend_from_slice(&spec.bit_depth.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(data);
+    wav
+}
+
+/// Read back the sample rate and bit depth a WAV file was generated with,
+/// or `None` if `wav` isn't a well-formed enough header to tell.
+fn read_wav_spec(wav: &[u8]) -> Option<ToneSpec> {
+    if wav.len() < 36 || &wav[0..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+        return None;
+    }
+    let sample_rate_hz = u32::from_le_bytes(wav[24..28].try_into().ok()?);
+    let bit_depth = u16::from_le_bytes(wav[34..36].try_into().ok()?);
+    Some(ToneSpec { sample_rate_hz, bit_depth })
+}
+
+/// Ensure `dir`'s generated base tone matches `spec`, regenerating it if
+/// missing or if it was generated with a different spec (detected by
+/// reading back its header) — deleting the stale file first so there's
+/// never a mismatched leftover on disk.
+pub fn ensure_base_pack_assets(dir: &Path, spec: ToneSpec) -> std::io::Result<()> {
+    let path = dir.join("base_beep.wav");
+    if let Ok(existing) = fs::read(&path) {
+        if read_wav_spec(&existing) == Some(spec) {
+            return Ok(());
+        }
+        fs::remove_file(&path)?;
+    }
+    fs::create_dir_all(dir)?;
+    fs::write(&path, generate_tone(880.0, Duration::from_millis(200), spec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_generated_header_reports_the_requested_sample_rate_and_bit_depth() {
+        let spec = ToneSpec { sample_rate_hz: 22_050, bit_depth: 8 };
+        let wav = generate_tone(440.0, Duration::from_millis(50), spec);
+
+        assert_eq!(read_wav_spec(&wav), Some(spec));
+    }
+
+    #[test]
+    fn the_default_spec_matches_the_previously_hardcoded_format() {
+        assert_eq!(ToneSpec::default(), ToneSpec { sample_rate_hz: 44_100, bit_depth: 16 });
+    }
+
+    #[test]
+    fn the_data_chunk_length_matches_the_requested_duration_and_bit_depth() {
+        let spec = ToneSpec { sample_rate_hz: 1_000, bit_depth: 16 };
+        let wav = generate_tone(100.0, Duration::from_secs(1), spec);
+
+        // 44-byte header + 1000 samples * 2 bytes/sample.
+        assert_eq!(wav.len(), 44 + 2_000);
+    }
+
+    fn test_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("scout-for-lol-test-tone-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn ensure_base_pack_assets_writes_a_tone_matching_the_requested_spec() {
+        let dir = test_dir("write");
+        let spec = ToneSpec { sample_rate_hz: 48_000, bit_depth: 16 };
+
+        ensure_base_pack_assets(&dir, spec).unwrap();
+
+        let written = fs::read(dir.join("base_beep.wav")).unwrap();
+        assert_eq!(read_wav_spec(&written), Some(spec));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn ensure_base_pack_assets_is_a_no_op_when_the_existing_tone_already_matches() {
+        let dir = test_dir("noop");
+        let spec = ToneSpec::default();
+        ensure_base_pack_assets(&dir, spec).unwrap();
+        let first_written = fs::metadata(dir.join("base_beep.wav")).unwrap().modified().unwrap();
+
+        std::thread::sleep(Duration::from_millis(10));
+        ensure_base_pack_assets(&dir, spec).unwrap();
+        let second_written = fs::metadata(dir.join("base_beep.wav")).unwrap().modified().unwrap();
+
+        assert_eq!(first_written, second_written);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn ensure_base_pack_assets_regenerates_a_stale_tone_when_the_spec_changes() {
+        let dir = test_dir("regen");
+        ensure_base_pack_assets(&dir, ToneSpec { sample_rate_hz: 44_100, bit_depth: 16 }).unwrap();
+
+        let new_spec = ToneSpec { sample_rate_hz: 8_000, bit_depth: 8 };
+        ensure_base_pack_assets(&dir, new_spec).unwrap();
+
+        let regenerated = fs::read(dir.join("base_beep.wav")).unwrap();
+        assert_eq!(read_wav_spec(&regenerated), Some(new_spec));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
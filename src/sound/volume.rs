@@ -0,0 +1,173 @@
+/// Config for boosting volume on "big play" events (pentakills, baron
+/// steals, etc.) so they cut through even if the user has turned down
+/// general playback volume.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BigPlayVolumeBoost {
+    pub enabled: bool,
+    pub multiplier: f32,
+}
+
+impl Default for BigPlayVolumeBoost {
+    fn default() -> Self {
+        BigPlayVolumeBoost {
+            enabled: true,
+            multiplier: 1.5,
+        }
+    }
+}
+
+impl BigPlayVolumeBoost {
+    /// Apply the boost to `base_volume` if `is_big_play` and boosting is
+    /// enabled, clamped to the valid [0.0, 1.0] output range.
+    pub fn apply(&self, base_volume: f32, is_big_play: bool) -> f32 {
+        let volume = if is_big_play && self.enabled {
+            base_volume * self.multiplier
+        } else {
+            base_volume
+        };
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+/// Config for boosting volume on the first kill of the game (see the
+/// `is_first_kill` flag returned by [`crate::game::KillTracker::record_kill`]),
+/// so that standout moment isn't lost among later, more routine kills.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FirstBloodVolumeBoost {
+    pub enabled: bool,
+    pub multiplier: f32,
+}
+
+impl Default for FirstBloodVolumeBoost {
+    fn default() -> Self {
+        FirstBloodVolumeBoost {
+            enabled: true,
+            multiplier: 1.5,
+        }
+    }
+}
+
+impl FirstBloodVolumeBoost {
+    /// Apply the boost to `base_volume` if `is_first_blood` and boosting is
+    /// enabled, clamped to the valid [0.0, 1.0] output range.
+    pub fn apply(&self, base_volume: f32, is_first_blood: bool) -> f32 {
+        let volume = if is_first_blood && self.enabled {
+            base_volume * self.multiplier
+        } else {
+            base_volume
+        };
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+/// Config for quieting (not muting) events while the local player is dead,
+/// so death still carries information without being as intrusive as a
+/// live callout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeadPlayerVolume {
+    pub dead_volume_scale: f32,
+}
+
+impl Default for DeadPlayerVolume {
+    fn default() -> Self {
+        DeadPlayerVolume { dead_volume_scale: 0.5 }
+    }
+}
+
+impl DeadPlayerVolume {
+    /// Scale `base_volume` down by `dead_volume_scale` while the local
+    /// player is dead; unchanged while alive. Clamped to [0.0, 1.0].
+    pub fn apply(&self, base_volume: f32, local_player_is_dead: bool) -> f32 {
+        let volume = if local_player_is_dead {
+            base_volume * self.dead_volume_scale
+        } else {
+            base_volume
+        };
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_big_plays_are_unaffected() {
+        let boost = BigPlayVolumeBoost::default();
+        assert_eq!(boost.apply(0.5, false), 0.5);
+    }
+
+    #[test]
+    fn big_plays_get_boosted() {
+        let boost = BigPlayVolumeBoost::default();
+        assert_eq!(boost.apply(0.5, true), 0.75);
+    }
+
+    #[test]
+    fn boost_is_clamped_to_max_volume() {
+        let boost = BigPlayVolumeBoost::default();
+        assert_eq!(boost.apply(0.9, true), 1.0);
+    }
+
+    #[test]
+    fn disabled_boost_leaves_volume_unchanged() {
+        let boost = BigPlayVolumeBoost { enabled: false, multiplier: 2.0 };
+        assert_eq!(boost.apply(0.5, true), 0.5);
+    }
+
+    #[test]
+    fn non_first_bloods_are_unaffected() {
+        let boost = FirstBloodVolumeBoost::default();
+        assert_eq!(boost.apply(0.5, false), 0.5);
+    }
+
+    #[test]
+    fn first_blood_gets_boosted() {
+        let boost = FirstBloodVolumeBoost::default();
+        assert_eq!(boost.apply(0.5, true), 0.75);
+    }
+
+    #[test]
+    fn first_blood_boost_is_clamped_to_max_volume() {
+        let boost = FirstBloodVolumeBoost::default();
+        assert_eq!(boost.apply(0.9, true), 1.0);
+    }
+
+    #[test]
+    fn disabled_first_blood_boost_leaves_volume_unchanged() {
+        let boost = FirstBloodVolumeBoost { enabled: false, multiplier: 2.0 };
+        assert_eq!(boost.apply(0.5, true), 0.5);
+    }
+
+    #[test]
+    fn first_kill_flag_from_the_kill_tracker_drives_the_boost() {
+        let mut tracker = crate::game::KillTracker::new();
+        let boost = FirstBloodVolumeBoost::default();
+
+        let (_, is_first_kill) = tracker.record_kill(0.0);
+        assert!(is_first_kill);
+        assert_eq!(boost.apply(0.5, is_first_kill), 0.75);
+
+        let (_, is_first_kill) = tracker.record_kill(5.0);
+        assert!(!is_first_kill);
+        assert_eq!(boost.apply(0.5, is_first_kill), 0.5);
+    }
+
+    #[test]
+    fn volume_is_unscaled_while_alive() {
+        let dead_volume = DeadPlayerVolume::default();
+        assert_eq!(dead_volume.apply(0.8, false), 0.8);
+    }
+
+    #[test]
+    fn volume_is_scaled_down_while_dead() {
+        let dead_volume = DeadPlayerVolume::default();
+        assert_eq!(dead_volume.apply(0.8, true), 0.4);
+    }
+
+    #[test]
+    fn dead_volume_scale_is_clamped_to_max_volume() {
+        let dead_volume = DeadPlayerVolume { dead_volume_scale: 2.0 };
+        assert_eq!(dead_volume.apply(0.8, true), 1.0);
+    }
+}
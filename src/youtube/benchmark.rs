@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+use super::{YtDlpError, KNOWN_GOOD_URL};
+
+/// Result of timing a single yt-dlp download against a known URL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadBenchmark {
+    pub elapsed: Duration,
+    pub bytes_written: u64,
+}
+
+impl DownloadBenchmark {
+    pub fn bytes_per_second(&self) -> f64 {
+        if self.elapsed.is_zero() {
+            return 0.0;
+        }
+        self.bytes_written as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+/// Download `url` (defaulting to [`KNOWN_GOOD_URL`]) to `dest` and time how
+/// long it takes, so users can tell a slow sound pack load from a slow
+/// connection.
+pub fn benchmark_download(
+    url: Option<&str>,
+    dest: &std::path::Path,
+) -> Result<DownloadBenchmark, YtDlpError> {
+    let url = url.unwrap_or(KNOWN_GOOD_URL);
+    let start = Instant::now();
+    let output = std::process::Command::new("yt-dlp")
+        .args(["-o", &dest.to_string_lossy(), url])
+        .output()
+        .map_err(YtDlpError::Spawn)?;
+
+    if !output.status.success() {
+        return Err(YtDlpError::NonZeroExit {
+            status: output.status.code().unwrap_or(-1),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+
+    let elapsed = start.elapsed();
+    let bytes_written = std::fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+    Ok(DownloadBenchmark { elapsed, bytes_written })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_per_second_is_zero_for_instant_downloads() {
+        let bench = DownloadBenchmark { elapsed: Duration::ZERO, bytes_written: 1000 };
+        assert_eq!(bench.bytes_per_second(), 0.0);
+    }
+
+    #[test]
+    fn bytes_per_second_divides_bytes_by_elapsed_time() {
+        let bench = DownloadBenchmark { elapsed: Duration::from_secs(2), bytes_written: 1000 };
+        assert_eq!(bench.bytes_per_second(), 500.0);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::sound::SoundPack;
+
+/// Collect every sound's `source_url` across all of a pack's pools,
+/// deduplicated, as the input to a bulk "download everything as a zip"
+/// operation.
+pub fn collect_referenced_urls(pack: &SoundPack) -> Vec<String> {
+    let mut urls: Vec<String> = pack
+        .pools
+        .values()
+        .flat_map(|pool| pool.sounds())
+        .filter_map(|sound| sound.source_url.clone())
+        .collect();
+    urls.sort();
+    urls.dedup();
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_pack(path: &std::path::Path) {
+        let json = r#"{
+            "id": "p",
+            "pools": {
+                "kill": {"mode": "random", "sounds": [
+                    {"id": "a", "url": "https://youtu.be/a"},
+                    {"id": "b", "url": "https://youtu.be/a"},
+                    {"id": "c"}
+                ]}
+            }
+        }"#;
+        let mut f = std::fs::File::create(path).unwrap();
+        f.write_all(json.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn collects_unique_referenced_urls() {
+        let dir = std::env::temp_dir().join(format!("scout-bulk-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sound-pack.json");
+        write_pack(&path);
+
+        let pack = SoundPack::load(&path).unwrap();
+        assert_eq!(collect_referenced_urls(&pack), vec!["https://youtu.be/a".to_string()]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// How many consecutive streaming failures a URL can rack up before it's
+/// marked broken and stops being retried every time its event fires.
+const STREAM_FAILURE_THRESHOLD: u32 = 3;
+
+/// Maps a YouTube URL to the local file it was downloaded to, kept
+/// in-memory so repeat lookups in a session skip re-resolving the URL.
+/// Separate from whatever lives on disk under the cache directory.
+#[derive(Debug, Default)]
+pub struct DownloadCache {
+    resolved: HashMap<String, PathBuf>,
+    stream_failures: HashMap<String, u32>,
+}
+
+impl DownloadCache {
+    pub fn new() -> Self {
+        DownloadCache::default()
+    }
+
+    pub fn get(&self, url: &str) -> Option<&PathBuf> {
+        self.resolved.get(url)
+    }
+
+    pub fn insert(&mut self, url: String, path: PathBuf) {
+        self.resolved.insert(url, path);
+    }
+
+    /// Drop all in-memory entries without touching anything on disk.
+    pub fn clear(&mut self) {
+        self.resolved.clear();
+        self.stream_failures.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.resolved.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resolved.is_empty()
+    }
+
+    /// Record that streaming `url` (the caching fallback) failed. Once a
+    /// URL has failed [`STREAM_FAILURE_THRESHOLD`] times it's considered
+    /// broken, so callers can fall back to the base beep instead of
+    /// retrying a doomed stream on every event.
+    pub fn record_stream_failure(&mut self, url: &str) {
+        *self.stream_failures.entry(url.to_string()).or_insert(0) += 1;
+    }
+
+    /// Whether `url` has failed streaming enough times to be given up on.
+    pub fn is_broken(&self, url: &str) -> bool {
+        self.stream_failures.get(url).is_some_and(|&count| count >= STREAM_FAILURE_THRESHOLD)
+    }
+
+    /// A successful resolution (stream or cache hit) clears any failure
+    /// history, so a transient outage doesn't permanently break a URL.
+    pub fn record_stream_success(&mut self, url: &str) {
+        self.stream_failures.remove(url);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_empties_the_map_without_touching_disk() {
+        let mut cache = DownloadCache::new();
+        cache.insert("https://youtu.be/a".into(), PathBuf::from("/tmp/a.mp3"));
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+
+        assert!(cache.is_empty());
+        assert!(cache.get("https://youtu.be/a").is_none());
+    }
+
+    #[test]
+    fn a_url_is_marked_broken_after_the_failure_threshold() {
+        let mut cache = DownloadCache::new();
+        let url = "https://youtu.be/broken";
+
+        cache.record_stream_failure(url);
+        cache.record_stream_failure(url);
+        assert!(!cache.is_broken(url));
+
+        cache.record_stream_failure(url);
+        assert!(cache.is_broken(url));
+    }
+
+    #[test]
+    fn a_successful_stream_clears_prior_failures() {
+        let mut cache = DownloadCache::new();
+        let url = "https://youtu.be/flaky";
+        for _ in 0..STREAM_FAILURE_THRESHOLD {
+            cache.record_stream_failure(url);
+        }
+        assert!(cache.is_broken(url));
+
+        cache.record_stream_success(url);
+
+        assert!(!cache.is_broken(url));
+    }
+
+    #[test]
+    fn urls_with_no_recorded_failures_are_not_broken() {
+        let cache = DownloadCache::new();
+        assert!(!cache.is_broken("https://youtu.be/never-tried"));
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Find groups of cached files with identical contents, keyed by content
+/// hash, so a dedupe pass can reclaim the disk space.
+pub fn find_duplicate_files(paths: &[PathBuf]) -> Vec<Vec<PathBuf>> {
+    let mut by_hash: HashMap<Vec<u8>, Vec<PathBuf>> = HashMap::new();
+    for path in paths {
+        if let Ok(contents) = std::fs::read(path) {
+            by_hash.entry(simple_hash(&contents)).or_default().push(path.clone());
+        }
+    }
+    by_hash.into_values().filter(|group| group.len() > 1).collect()
+}
+
+/// Remove every duplicate in each group but the first, returning how many
+/// files were deleted.
+pub fn dedupe_cache(paths: &[PathBuf]) -> std::io::Result<usize> {
+    let mut removed = 0;
+    for group in find_duplicate_files(paths) {
+        for duplicate in &group[1..] {
+            std::fs::remove_file(duplicate)?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Cheap content hash — good enough to group duplicates without pulling in
+/// a cryptographic hashing dependency for what's just a local file compare.
+fn simple_hash(bytes: &[u8]) -> Vec<u8> {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish().to_le_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn write(dir: &Path, name: &str, contents: &[u8]) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn dedupe_removes_all_but_one_copy_per_group() {
+        let dir = std::env::temp_dir().join(format!("scout-dedupe-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let a = write(&dir, "a.mp3", b"same bytes");
+        let b = write(&dir, "b.mp3", b"same bytes");
+        let c = write(&dir, "c.mp3", b"different bytes");
+
+        let removed = dedupe_cache(&[a.clone(), b.clone(), c.clone()]).unwrap();
+
+        assert_eq!(removed, 1);
+        assert!(a.exists());
+        assert!(!b.exists());
+        assert!(c.exists());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
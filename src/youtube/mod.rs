@@ -0,0 +1,121 @@
+pub mod benchmark;
+pub mod bulk_export;
+pub mod cache;
+pub mod dedupe;
+
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+pub use benchmark::{benchmark_download, DownloadBenchmark};
+pub use bulk_export::collect_referenced_urls;
+pub use cache::DownloadCache;
+pub use dedupe::{dedupe_cache, find_duplicate_files};
+
+/// A URL known to resolve on YouTube, used to sanity-check that `yt-dlp`
+/// is installed and working without depending on any specific sound pack.
+pub const KNOWN_GOOD_URL: &str = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+
+#[derive(Debug, thiserror::Error)]
+pub enum YtDlpError {
+    #[error("failed to launch yt-dlp: {0}")]
+    Spawn(#[source] std::io::Error),
+    #[error("yt-dlp exited with status {status}: {stderr}")]
+    NonZeroExit { status: i32, stderr: String },
+}
+
+/// Run `yt-dlp --simulate` against [`KNOWN_GOOD_URL`] to confirm the binary
+/// is installed, on PATH, and can actually resolve a video (as opposed to
+/// just printing `--version`).
+pub fn test_yt_dlp() -> Result<(), YtDlpError> {
+    test_yt_dlp_against(KNOWN_GOOD_URL)
+}
+
+fn test_yt_dlp_against(url: &str) -> Result<(), YtDlpError> {
+    let output = Command::new("yt-dlp")
+        .args(["--simulate", "--quiet", url])
+        .output()
+        .map_err(YtDlpError::Spawn)?;
+
+    if !output.status.success() {
+        return Err(YtDlpError::NonZeroExit {
+            status: output.status.code().unwrap_or(-1),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// Outcome of [`test_ytdlp_download`], staged so diagnostics can tell
+/// "yt-dlp isn't installed" apart from "yt-dlp ran but this download
+/// failed" instead of collapsing both into one opaque failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YtDlpDownloadResult {
+    pub binary_found: bool,
+    pub download_ok: bool,
+    pub elapsed: Duration,
+}
+
+/// Download a tiny known-good video to a temp file and time it, verifying
+/// the whole yt-dlp pipeline works on the user's machine rather than just
+/// that the binary exists. The temp file is removed afterward regardless
+/// of outcome.
+pub fn test_ytdlp_download() -> YtDlpDownloadResult {
+    let dest = std::env::temp_dir().join(format!("scout-for-lol-ytdlp-download-test-{}.mp4", std::process::id()));
+    let start = Instant::now();
+    let output = Command::new("yt-dlp").args(["-o", &dest.to_string_lossy(), KNOWN_GOOD_URL]).output();
+    let elapsed = start.elapsed();
+
+    let result = match &output {
+        Ok(output) => stage_download_result(true, output.status.success(), dest.exists(), elapsed),
+        Err(_) => stage_download_result(false, false, false, elapsed),
+    };
+
+    let _ = std::fs::remove_file(&dest);
+    result
+}
+
+fn stage_download_result(
+    binary_found: bool,
+    command_exit_ok: bool,
+    file_written: bool,
+    elapsed: Duration,
+) -> YtDlpDownloadResult {
+    YtDlpDownloadResult {
+        binary_found,
+        download_ok: binary_found && command_exit_ok && file_written,
+        elapsed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_missing_binary_is_staged_as_binary_not_found() {
+        let result = stage_download_result(false, false, false, Duration::ZERO);
+        assert!(!result.binary_found);
+        assert!(!result.download_ok);
+    }
+
+    #[test]
+    fn a_found_binary_that_fails_to_download_is_staged_accordingly() {
+        let result = stage_download_result(true, false, false, Duration::ZERO);
+        assert!(result.binary_found);
+        assert!(!result.download_ok);
+    }
+
+    #[test]
+    fn a_successful_download_reports_both_stages_ok_and_the_elapsed_time() {
+        let result = stage_download_result(true, true, true, Duration::from_secs(1));
+        assert!(result.binary_found);
+        assert!(result.download_ok);
+        assert_eq!(result.elapsed, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn a_zero_exit_with_no_file_written_is_not_a_successful_download() {
+        let result = stage_download_result(true, true, false, Duration::ZERO);
+        assert!(!result.download_ok);
+    }
+}